@@ -9,9 +9,14 @@ import typing_extensions
 class Error(Exception): ...
 
 Resolver: typing_extensions.TypeAlias = (
-    IntegerResolver | StringResolver | EntityResolver
+    ChoiceResolver
+    | FloatResolver
+    | IntegerResolver
+    | NumericStringResolver
+    | StringResolver
+    | EntityResolver
 )
-PathValue: typing_extensions.TypeAlias = int | str
+PathValue: typing_extensions.TypeAlias = int | float | str
 TemplateValue: typing_extensions.TypeAlias = (
     None | bool | int | float | str | list[TemplateValue] | dict[str, TemplateValue]
 )
@@ -25,18 +30,28 @@ class Config:
         resolvers: collections.abc.Mapping[str, Resolver],
         path_items: collections.abc.Iterable[PathItem],
     ) -> None: ...
+    def description_for(self, key: str) -> str | None: ...
 
 class FieldKey:
     def __init__(self, key: str) -> None: ...
 
+class ChoiceResolver:
+    def __init__(self, options: collections.abc.Sequence[str]) -> None: ...
+
 class EntityResolver:
     def __init__(self, key: str) -> None: ...
 
+class FloatResolver:
+    def __init__(self, precision: int) -> None: ...
+
 class IntegerResolver:
-    def __init__(self, padding: int) -> None: ...
+    def __init__(self, padding: int, group_separator: str | None = None) -> None: ...
+
+class NumericStringResolver:
+    def __init__(self) -> None: ...
 
 class StringResolver:
-    def __init__(self, pattern: str | None) -> None: ...
+    def __init__(self, pattern: str | None, percent_encoded: bool = False) -> None: ...
 
 class Owner(enum.Enum):
     Inherit = enum.auto()
@@ -50,20 +65,24 @@ class PathItem:
         key: str,
         path: os.PathLike | str,
         parent: str | None,
-        permission: Permission,
-        owner: Owner,
+        permission: Permission | str,
+        owner: Owner | str,
         path_type: PathType,
         deferred: bool,
         metadata: collections.abc.Mapping[str, MetadataValue],
+        order: int = 0,
+        description: str | None = None,
     ) -> None: ...
 
 class ResolvedPathItem:
     def key(self) -> str | None: ...
     def value(self) -> pathlib.Path: ...
-    def permission(self) -> Permission: ...
-    def owner(self) -> Owner: ...
+    def to_posix_string(self) -> str: ...
+    def permission(self) -> Permission | str: ...
+    def owner(self) -> Owner | str: ...
     def path_type(self) -> PathType: ...
     def deferred(self) -> bool: ...
+    def order(self) -> int: ...
     def metadata(self) -> dict[str, MetadataValue]: ...
 
 class PathType(enum.Enum):
@@ -24,8 +24,9 @@ pub use errors::Error;
 pub(crate) use errors::to_py_result;
 pub use path_resolver::{find_paths, get_fields, get_key, get_path};
 pub use types::{
-    Config, FieldKey, IntegerResolver, MetadataValue, Owner, PathItem, PathType, PathValue,
-    Permission, ResolvedPathItem, StringResolver, TemplateValue,
+    ChoiceResolver, Config, FieldKey, FloatResolver, IntegerResolver, MetadataValue,
+    NumericStringResolver, Owner, PathItem, PathType, PathValue, Permission, ResolvedPathItem,
+    StringResolver, TemplateValue,
 };
 pub use workspace_resolver::{create_workspace, get_workspace};
 
@@ -48,8 +49,8 @@ pub mod openpathresolver {
     // Types
     #[pymodule_export]
     use super::{
-        Config, FieldKey, IntegerResolver, Owner, PathItem, PathType, Permission, ResolvedPathItem,
-        StringResolver,
+        ChoiceResolver, Config, FieldKey, FloatResolver, IntegerResolver, NumericStringResolver,
+        Owner, PathItem, PathType, Permission, ResolvedPathItem, StringResolver,
     };
 
     // Functions
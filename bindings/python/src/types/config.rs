@@ -31,13 +31,34 @@ impl Config {
         for (key, resolver) in resolvers {
             if resolver.is_instance_of::<crate::StringResolver>() {
                 let resolver = resolver.extract::<crate::StringResolver>()?;
-                builder = builder
-                    .add_string_resolver(key, resolver.pattern.as_deref())
-                    .map_err(|err| to_py_error(&err))?;
+                builder = if resolver.percent_encoded {
+                    builder.add_percent_encoded_string_resolver(key, resolver.pattern.as_deref())
+                } else {
+                    builder.add_string_resolver(key, resolver.pattern.as_deref())
+                }
+                .map_err(|err| to_py_error(&err))?;
             } else if resolver.is_instance_of::<crate::IntegerResolver>() {
                 let resolver = resolver.extract::<crate::IntegerResolver>()?;
+                builder = match resolver.group_separator {
+                    Some(group_separator) => {
+                        builder.add_grouped_integer_resolver(key, resolver.padding, group_separator)
+                    }
+                    None => builder.add_integer_resolver(key, resolver.padding),
+                }
+                .map_err(|err| to_py_error(&err))?;
+            } else if resolver.is_instance_of::<crate::FloatResolver>() {
+                let resolver = resolver.extract::<crate::FloatResolver>()?;
+                builder = builder
+                    .add_float_resolver(key, resolver.precision)
+                    .map_err(|err| to_py_error(&err))?;
+            } else if resolver.is_instance_of::<crate::ChoiceResolver>() {
+                let resolver = resolver.extract::<crate::ChoiceResolver>()?;
                 builder = builder
-                    .add_integer_resolver(key, resolver.padding)
+                    .add_choice_resolver(key, resolver.options)
+                    .map_err(|err| to_py_error(&err))?;
+            } else if resolver.is_instance_of::<crate::NumericStringResolver>() {
+                builder = builder
+                    .add_numeric_string_resolver(key)
                     .map_err(|err| to_py_error(&err))?;
             } else {
                 return Err(PyNotImplementedError::new_err(format!(
@@ -63,6 +84,8 @@ impl Config {
                     owner: path_item.owner.into(),
                     path_type: path_item.path_type.into(),
                     deferred: path_item.deferred,
+                    order: path_item.order,
+                    description: path_item.description,
                     metadata: path_item
                         .metadata
                         .into_iter()
@@ -78,4 +101,15 @@ impl Config {
             inner: std::sync::Arc::new(config),
         })
     }
+
+    /// Get the description of the key's path item, if it has one.
+    ///
+    /// Args:
+    ///     key: The key of the path item.
+    fn description_for(&self, key: &str) -> PyResult<Option<String>> {
+        self.inner
+            .description_for(key)
+            .map(|description| description.map(str::to_string))
+            .map_err(|err| to_py_error(&err))
+    }
 }
@@ -7,5 +7,7 @@ mod value;
 pub use config::Config;
 pub use field_key::FieldKey;
 pub use path_item::{Owner, PathItem, PathType, Permission, ResolvedPathItem};
-pub use resolver::{IntegerResolver, StringResolver};
+pub use resolver::{
+    ChoiceResolver, FloatResolver, IntegerResolver, NumericStringResolver, StringResolver,
+};
 pub use value::{MetadataValue, PathValue, TemplateValue};
@@ -56,24 +56,35 @@ pub enum Owner {
     User,
 }
 
-impl From<base_openpathresolver::Owner> for Owner {
+/// The owner of the path, or the name of a site specific owner.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, FromPyObject, IntoPyObject)]
+pub enum OwnerValue {
+    /// One of the built in owners.
+    Owner(Owner),
+    /// A site specific owner, such as an "editorial" group.
+    Custom(String),
+}
+
+impl From<base_openpathresolver::Owner> for OwnerValue {
     fn from(value: base_openpathresolver::Owner) -> Self {
         match value {
-            base_openpathresolver::Owner::Inherit => Self::Inherit,
-            base_openpathresolver::Owner::Root => Self::Root,
-            base_openpathresolver::Owner::Project => Self::Project,
-            base_openpathresolver::Owner::User => Self::User,
+            base_openpathresolver::Owner::Inherit => Self::Owner(Owner::Inherit),
+            base_openpathresolver::Owner::Root => Self::Owner(Owner::Root),
+            base_openpathresolver::Owner::Project => Self::Owner(Owner::Project),
+            base_openpathresolver::Owner::User => Self::Owner(Owner::User),
+            base_openpathresolver::Owner::Custom(value) => Self::Custom(value),
         }
     }
 }
 
-impl From<Owner> for base_openpathresolver::Owner {
-    fn from(value: Owner) -> Self {
+impl From<OwnerValue> for base_openpathresolver::Owner {
+    fn from(value: OwnerValue) -> Self {
         match value {
-            Owner::Inherit => Self::Inherit,
-            Owner::Root => Self::Root,
-            Owner::Project => Self::Project,
-            Owner::User => Self::User,
+            OwnerValue::Owner(Owner::Inherit) => Self::Inherit,
+            OwnerValue::Owner(Owner::Root) => Self::Root,
+            OwnerValue::Owner(Owner::Project) => Self::Project,
+            OwnerValue::Owner(Owner::User) => Self::User,
+            OwnerValue::Custom(value) => Self::Custom(value),
         }
     }
 }
@@ -96,22 +107,33 @@ pub enum Permission {
     ReadWrite,
 }
 
-impl From<base_openpathresolver::Permission> for Permission {
+/// The permission for a path, or the name of a site specific permission.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, FromPyObject, IntoPyObject)]
+pub enum PermissionValue {
+    /// One of the built in permissions.
+    Permission(Permission),
+    /// A site specific permission, such as an ACL group.
+    Custom(String),
+}
+
+impl From<base_openpathresolver::Permission> for PermissionValue {
     fn from(value: base_openpathresolver::Permission) -> Self {
         match value {
-            base_openpathresolver::Permission::Inherit => Self::Inherit,
-            base_openpathresolver::Permission::ReadOnly => Self::ReadOnly,
-            base_openpathresolver::Permission::ReadWrite => Self::ReadWrite,
+            base_openpathresolver::Permission::Inherit => Self::Permission(Permission::Inherit),
+            base_openpathresolver::Permission::ReadOnly => Self::Permission(Permission::ReadOnly),
+            base_openpathresolver::Permission::ReadWrite => Self::Permission(Permission::ReadWrite),
+            base_openpathresolver::Permission::Custom(value) => Self::Custom(value),
         }
     }
 }
 
-impl From<Permission> for base_openpathresolver::Permission {
-    fn from(value: Permission) -> Self {
+impl From<PermissionValue> for base_openpathresolver::Permission {
+    fn from(value: PermissionValue) -> Self {
         match value {
-            Permission::Inherit => Self::Inherit,
-            Permission::ReadOnly => Self::ReadOnly,
-            Permission::ReadWrite => Self::ReadWrite,
+            PermissionValue::Permission(Permission::Inherit) => Self::Inherit,
+            PermissionValue::Permission(Permission::ReadOnly) => Self::ReadOnly,
+            PermissionValue::Permission(Permission::ReadWrite) => Self::ReadWrite,
+            PermissionValue::Custom(value) => Self::Custom(value),
         }
     }
 }
@@ -151,12 +173,17 @@ impl ResolvedPathItem {
         self.inner.value()
     }
 
+    /// The fully resolved path, always rendered with `/` separators.
+    pub fn to_posix_string(&self) -> String {
+        self.inner.to_posix_string()
+    }
+
     /// The permission for the path.
     ///
     /// There should be no inherited permissions at this point unless no permissions have been
     /// defined at all. Then it is recommended to have the workspace resolver decide what the "root" permission should be.
-    pub fn permission(&self) -> Permission {
-        Permission::from(self.inner.permission().to_owned())
+    pub fn permission(&self) -> PermissionValue {
+        PermissionValue::from(self.inner.permission().to_owned())
     }
 
     /// The owner of the path.
@@ -164,8 +191,8 @@ impl ResolvedPathItem {
     /// There should be no inherited owner at this point, unless no owner has been defined at all.
     /// Then it is recommended to have the workspace resolver decide what the "root" owner should
     /// be.
-    pub fn owner(&self) -> Owner {
-        Owner::from(self.inner.owner().to_owned())
+    pub fn owner(&self) -> OwnerValue {
+        OwnerValue::from(self.inner.owner().to_owned())
     }
 
     /// The type of the path.
@@ -183,6 +210,13 @@ impl ResolvedPathItem {
         self.inner.deferred()
     }
 
+    /// The creation order of the path relative to its siblings.
+    ///
+    /// Lower orders are created first.
+    pub fn order(&self) -> i32 {
+        self.inner.order()
+    }
+
     /// Metadata for the workspace resolver.
     ///
     /// This could contain anything such as the specific user  that owns the path, or the source
@@ -203,11 +237,13 @@ pub struct PathItem {
     pub(crate) key: crate::FieldKey,
     pub(crate) path: std::path::PathBuf,
     pub(crate) parent: Option<crate::FieldKey>,
-    pub(crate) permission: Permission,
-    pub(crate) owner: Owner,
+    pub(crate) permission: PermissionValue,
+    pub(crate) owner: OwnerValue,
     pub(crate) path_type: PathType,
     pub(crate) deferred: bool,
     pub(crate) metadata: std::collections::HashMap<String, crate::MetadataValue>,
+    pub(crate) order: i32,
+    pub(crate) description: Option<String>,
 }
 
 #[pymethods]
@@ -220,8 +256,10 @@ impl PathItem {
     ///         It is recommended that all path parts are always relative, and to have the root of the
     ///         path defined as a placeholder.
     ///     parent: The parent path item's field key.
-    ///     permission: The permission of the path and all child paths unless explicitly set.
-    ///     owner: The owner of the path and all child paths unless explicitly set.
+    ///     permission: The permission of the path and all child paths unless explicitly set. A
+    ///         string is a site specific permission, such as an ACL group.
+    ///     owner: The owner of the path and all child paths unless explicitly set. A string is a
+    ///         site specific owner, such as an "editorial" group.
     ///     path_type: The path_type of the path and all child paths unless explicitly set.
     ///     deferred: Whether the path is deferred or not. If the path is deferred, then the
     ///         :code:`create_workspace` function will not resolve the path unless a subpath is
@@ -232,17 +270,24 @@ impl PathItem {
     ///         valid, then the path will be resolved.
     ///     metadata: Extra metadata for the arguments that may be useful, as as marking a path as
     ///     belonging to a specific user.
+    ///     order: The creation order of the path relative to its siblings. Lower orders are created
+    ///         first.
+    ///     description: A human readable description of the path, such as for generated
+    ///         documentation or tooltips.
     #[allow(clippy::too_many_arguments)]
     #[new]
+    #[pyo3(signature = (key, path, parent, permission, owner, path_type, deferred, metadata, order=0, description=None))]
     fn new(
         key: String,
         path: std::path::PathBuf,
         parent: Option<String>,
-        permission: Permission,
-        owner: Owner,
+        permission: PermissionValue,
+        owner: OwnerValue,
         path_type: PathType,
         deferred: bool,
         metadata: std::collections::HashMap<String, crate::MetadataValue>,
+        order: i32,
+        description: Option<String>,
     ) -> PyResult<Self> {
         let key = crate::FieldKey::try_from(key)?;
 
@@ -260,6 +305,8 @@ impl PathItem {
             path_type,
             deferred,
             metadata,
+            order,
+            description,
         })
     }
 }
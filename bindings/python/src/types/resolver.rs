@@ -5,6 +5,7 @@ use pyo3::prelude::*;
 #[pyclass]
 pub struct StringResolver {
     pub(crate) pattern: Option<String>,
+    pub(crate) percent_encoded: bool,
 }
 
 #[pymethods]
@@ -13,9 +14,15 @@ impl StringResolver {
     ///
     /// Args:
     ///     pattern: The regex pattern to define the shape of the string.
+    ///     percent_encoded: Whether the string is percent-encoded in the path, such as `%20` for a
+    ///         space.
     #[new]
-    fn new(pattern: Option<String>) -> Self {
-        Self { pattern }
+    #[pyo3(signature = (pattern, percent_encoded=false))]
+    fn new(pattern: Option<String>, percent_encoded: bool) -> Self {
+        Self {
+            pattern,
+            percent_encoded,
+        }
     }
 
     /// The shape of the string.
@@ -23,6 +30,12 @@ impl StringResolver {
     fn pattern(&self) -> &Option<String> {
         &self.pattern
     }
+
+    /// Whether the string is percent-encoded in the path.
+    #[getter]
+    fn percent_encoded(&self) -> bool {
+        self.percent_encoded
+    }
 }
 
 /// An integer resolver marks a placeholder as an integer with zero padding.
@@ -30,6 +43,7 @@ impl StringResolver {
 #[pyclass]
 pub struct IntegerResolver {
     pub(crate) padding: u8,
+    pub(crate) group_separator: Option<char>,
 }
 
 #[pymethods]
@@ -38,9 +52,14 @@ impl IntegerResolver {
     ///
     /// Args:
     ///     padding: The zero padding for the integer.
+    ///     group_separator: The separator to insert between every three digits, such as `1_000`.
     #[new]
-    fn new(padding: u8) -> Self {
-        Self { padding }
+    #[pyo3(signature = (padding, group_separator=None))]
+    fn new(padding: u8, group_separator: Option<char>) -> Self {
+        Self {
+            padding,
+            group_separator,
+        }
     }
 
     /// The zero padding for the integer.
@@ -48,4 +67,76 @@ impl IntegerResolver {
     fn padding(&self) -> u8 {
         self.padding
     }
+
+    /// The separator between every three digits.
+    #[getter]
+    fn group_separator(&self) -> Option<char> {
+        self.group_separator
+    }
+}
+
+/// A float resolver marks a placeholder as a float with a fixed number of decimal places.
+#[derive(Debug, FromPyObject)]
+#[pyclass]
+pub struct FloatResolver {
+    pub(crate) precision: u8,
+}
+
+#[pymethods]
+impl FloatResolver {
+    /// Create a new float resolver.
+    ///
+    /// Args:
+    ///     precision: The number of digits after the decimal point.
+    #[new]
+    fn new(precision: u8) -> Self {
+        Self { precision }
+    }
+
+    /// The number of digits after the decimal point.
+    #[getter]
+    fn precision(&self) -> u8 {
+        self.precision
+    }
+}
+
+/// A choice resolver marks a placeholder as a string that must be one of the options.
+#[derive(Debug, FromPyObject)]
+#[pyclass]
+pub struct ChoiceResolver {
+    pub(crate) options: Vec<String>,
+}
+
+#[pymethods]
+impl ChoiceResolver {
+    /// Create a new choice resolver.
+    ///
+    /// Args:
+    ///     options: The allowed values, such as `["model", "rig", "anim"]`.
+    #[new]
+    fn new(options: Vec<String>) -> Self {
+        Self { options }
+    }
+
+    /// The allowed values.
+    #[getter]
+    fn options(&self) -> Vec<String> {
+        self.options.clone()
+    }
+}
+
+/// A numeric string resolver marks a placeholder as a string of digits, such as a very large ID.
+///
+/// The value only matches digits, but it is kept as a string so it is never parsed into an integer.
+#[derive(Debug)]
+#[pyclass]
+pub struct NumericStringResolver {}
+
+#[pymethods]
+impl NumericStringResolver {
+    /// Create a new numeric string resolver.
+    #[new]
+    fn new() -> Self {
+        Self {}
+    }
 }
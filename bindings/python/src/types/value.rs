@@ -1,6 +1,6 @@
 use pyo3::{
     IntoPyObjectExt,
-    exceptions::PyTypeError,
+    exceptions::{PyTypeError, PyValueError},
     prelude::*,
     types::{PyBool, PyFloat, PyInt},
 };
@@ -24,13 +24,23 @@ impl<'py> FromPyObject<'_, 'py> for PathValue {
         if let Ok(value) = value.extract::<u16>() {
             let inner = base_openpathresolver::PathValue::Integer(value);
             Ok(Self { inner })
+        } else if value.is_instance_of::<PyFloat>() {
+            let inner = base_openpathresolver::PathValue::Float(value.extract::<f64>()?);
+            Ok(Self { inner })
         } else if let Ok(value) = value.extract::<String>() {
             let inner = base_openpathresolver::PathValue::String(value);
             Ok(Self { inner })
+        } else if value.is_instance_of::<PyInt>() {
+            Err(PyValueError::new_err(format!(
+                "Integer {} is out of range for a path field. Expected an integer from {} to {}.",
+                value.str()?,
+                u16::MIN,
+                u16::MAX
+            )))
         } else {
             let name = value.get_type().name()?;
             Err(PyTypeError::new_err(format!(
-                "Type '{}' is not supported. Expected an integer or a string.",
+                "Type '{}' is not supported. Expected an integer, a float, or a string.",
                 name
             )))
         }
@@ -99,10 +109,8 @@ impl<'py> FromPyObject<'_, 'py> for TemplateValue {
                 value.iter().map(|v| v.inner.clone()).collect(),
             );
             Ok(Self { inner })
-        } else if let Ok(value) =
-            value.extract::<std::collections::HashMap<String, TemplateValue>>()
-        {
-            let mut attributes = std::collections::HashMap::new();
+        } else if let Ok(value) = value.extract::<indexmap::IndexMap<String, TemplateValue>>() {
+            let mut attributes = indexmap::IndexMap::with_capacity(value.len());
 
             for (k, v) in value.iter() {
                 attributes.insert(crate::to_py_result(k.try_into())?, v.inner.clone());
@@ -237,7 +245,10 @@ pub(crate) fn path_value_to_py_any<'py>(
 ) -> PyResult<Bound<'py, PyAny>> {
     match value {
         base_openpathresolver::PathValue::Integer(value) => value.into_bound_py_any(py),
+        base_openpathresolver::PathValue::Float(value) => value.into_bound_py_any(py),
         base_openpathresolver::PathValue::String(value) => value.into_bound_py_any(py),
+        // Values from other features, such as dates, are passed to Python as their strings.
+        value => value.to_string().into_bound_py_any(py),
     }
 }
 
@@ -259,7 +270,7 @@ pub(crate) fn template_value_to_py_any<'py>(
             new_value.into_bound_py_any(py)
         }
         base_openpathresolver::TemplateValue::Object(value) => {
-            let mut new_value = std::collections::HashMap::with_capacity(value.len());
+            let mut new_value = indexmap::IndexMap::with_capacity(value.len());
             for (k, v) in value {
                 new_value.insert(
                     crate::FieldKey { inner: k },
@@ -1,12 +1,9 @@
 from __future__ import annotations
 
 import pathlib
-from typing import TYPE_CHECKING
 
 import openpathresolver
-
-if TYPE_CHECKING:
-    import pytest
+import pytest
 
 
 def test_get_path_success() -> None:
@@ -41,6 +38,30 @@ def test_get_path_success() -> None:
     assert path == pathlib.Path("path/to/003/test_other_test")
 
 
+def test_get_path_failure_integer_out_of_range() -> None:
+    config = openpathresolver.Config(
+        {"int": openpathresolver.IntegerResolver(3)},
+        [
+            openpathresolver.PathItem(
+                "path",
+                "path/to/{int}",
+                None,
+                openpathresolver.Permission.Inherit,
+                openpathresolver.Owner.Inherit,
+                openpathresolver.PathType.Directory,
+                deferred=False,
+                metadata={},
+            )
+        ],
+    )
+
+    with pytest.raises(
+        ValueError,
+        match="Integer 70000 is out of range for a path field. Expected an integer from 0 to 65535.",
+    ):
+        openpathresolver.get_path(config, "path", {"int": 70000})
+
+
 def test_get_fields_success() -> None:
     config = openpathresolver.Config(
         {
@@ -71,6 +92,30 @@ def test_get_fields_success() -> None:
     }
 
 
+def test_get_fields_float_round_trip_success() -> None:
+    config = openpathresolver.Config(
+        {"fps": openpathresolver.FloatResolver(3)},
+        [
+            openpathresolver.PathItem(
+                "path",
+                "path/to/{fps}",
+                None,
+                openpathresolver.Permission.Inherit,
+                openpathresolver.Owner.Inherit,
+                openpathresolver.PathType.Directory,
+                deferred=False,
+                metadata={},
+            )
+        ],
+    )
+
+    path = openpathresolver.get_path(config, "path", {"fps": 23.976})
+    assert path == pathlib.Path("path/to/23.976")
+
+    fields = openpathresolver.get_fields(config, "path", path)
+    assert fields == {"fps": 23.976}
+
+
 def test_get_key_success() -> None:
     config = openpathresolver.Config(
         {
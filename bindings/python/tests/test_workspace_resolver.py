@@ -162,6 +162,34 @@ def test_get_workspace_success(
     assert expected == sorted([i.value() for i in result])
 
 
+def test_get_workspace_custom_owner_permission_success(
+    tmp_path_factory: pytest.TempPathFactory,
+) -> None:
+    tmp_root = tmp_path_factory.mktemp("root")
+
+    config = openpathresolver.Config(
+        {},
+        [
+            openpathresolver.PathItem(
+                "path",
+                "{root}/path",
+                None,
+                "editorial_rw",
+                "editorial",
+                openpathresolver.PathType.Directory,
+                deferred=False,
+                metadata={},
+            )
+        ],
+    )
+
+    result = openpathresolver.get_workspace(config, {"root": tmp_root.as_posix()})
+    item = next(i for i in result if i.key() == "path")
+
+    assert item.permission() == "editorial_rw"
+    assert item.owner() == "editorial"
+
+
 def test_create_workspace_regression_segfault(
     tmp_path_factory: pytest.TempPathFactory,
 ) -> None:
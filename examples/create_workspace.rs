@@ -40,6 +40,8 @@ async fn main() {
             // If a path is deferred, then it will not be generated unless a child path is not
             // deferred and can be resolved.
             deferred: false,
+            order: 0,
+            description: None,
             // Extra metadata that might be useful for the IO function such as the path to copy the
             // file from.
             metadata: std::collections::HashMap::new(),
@@ -54,6 +56,8 @@ async fn main() {
             owner: openpathresolver::Owner::default(),
             path_type: openpathresolver::PathType::default(),
             deferred: false,
+            order: 0,
+            description: None,
             metadata: std::collections::HashMap::new(),
         })
         .unwrap()
@@ -65,6 +69,8 @@ async fn main() {
             owner: openpathresolver::Owner::default(),
             path_type: openpathresolver::PathType::default(),
             deferred: false,
+            order: 0,
+            description: None,
             metadata: std::collections::HashMap::new(),
         })
         .unwrap()
@@ -76,6 +82,8 @@ async fn main() {
             owner: openpathresolver::Owner::default(),
             path_type: openpathresolver::PathType::default(),
             deferred: false,
+            order: 0,
+            description: None,
             metadata: std::collections::HashMap::new(),
         })
         .unwrap()
@@ -89,6 +97,8 @@ async fn main() {
             owner: openpathresolver::Owner::default(),
             path_type: openpathresolver::PathType::default(),
             deferred: false,
+            order: 0,
+            description: None,
             metadata: std::collections::HashMap::new(),
         })
         .unwrap()
@@ -104,6 +114,8 @@ async fn main() {
             owner: openpathresolver::Owner::default(),
             path_type: openpathresolver::PathType::default(),
             deferred: false,
+            order: 0,
+            description: None,
             metadata: std::collections::HashMap::new(),
         })
         .unwrap()
@@ -42,6 +42,8 @@ fn main() {
             owner: openpathresolver::Owner::default(),
             path_type: openpathresolver::PathType::default(),
             deferred: false,
+            order: 0,
+            description: None,
             metadata: std::collections::HashMap::new(),
         })
         .unwrap()
@@ -59,6 +61,8 @@ fn main() {
             owner: openpathresolver::Owner::default(),
             path_type: openpathresolver::PathType::File,
             deferred: false,
+            order: 0,
+            description: None,
             metadata: std::collections::HashMap::new(),
         })
         .unwrap()
@@ -26,6 +26,8 @@ fn main() {
             owner: openpathresolver::Owner::default(),
             path_type: openpathresolver::PathType::default(),
             deferred: false,
+            order: 0,
+            description: None,
             metadata: std::collections::HashMap::new(),
         })
         .unwrap()
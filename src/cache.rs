@@ -5,20 +5,87 @@ use cached::Cached;
 #[allow(clippy::type_complexity)]
 static REGEX_CACHE: std::sync::LazyLock<
     std::sync::Mutex<
-        cached::SizedCache<String, Result<std::sync::Arc<regex::Regex>, regex::Error>>,
+        cached::SizedCache<
+            (String, Option<usize>),
+            Result<std::sync::Arc<regex::Regex>, regex::Error>,
+        >,
     >,
 > = std::sync::LazyLock::new(|| std::sync::Mutex::new(cached::SizedCache::with_size(512)));
 
 pub(crate) fn regex(pattern: &str) -> Result<std::sync::Arc<regex::Regex>, crate::Error> {
+    cached_regex(pattern, None)?
+        .map_err(|err| crate::Error::new(format!("Regex compile error: {err}")))
+}
+
+/// Get the compiled regex, with a limit on the compiled size instead of the regex crate's default.
+///
+/// The regex error is returned as it is, so the caller can report a pattern that is too large.
+pub(crate) fn regex_with_size_limit(
+    pattern: &str,
+    size_limit: usize,
+) -> Result<Result<std::sync::Arc<regex::Regex>, regex::Error>, crate::Error> {
+    cached_regex(pattern, Some(size_limit))
+}
+
+fn cached_regex(
+    pattern: &str,
+    size_limit: Option<usize>,
+) -> Result<Result<std::sync::Arc<regex::Regex>, regex::Error>, crate::Error> {
     let mut cache = REGEX_CACHE
         .lock()
         .map_err(|_| crate::Error::new("Mutex lock error"))?;
 
-    cache
-        .cache_get_or_set_with(pattern.to_string(), || {
-            regex::Regex::new(pattern).map(std::sync::Arc::new)
+    Ok(cache
+        .cache_get_or_set_with((pattern.to_string(), size_limit), || {
+            let mut builder = regex::RegexBuilder::new(pattern);
+
+            if let Some(size_limit) = size_limit {
+                builder.size_limit(size_limit);
+            }
+
+            builder.build().map(std::sync::Arc::new)
         })
-        .as_ref()
-        .map(|regex| regex.clone())
-        .map_err(|err| crate::Error::new(format!("Regex compile error: {err}")))
+        .clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_reuses_compiled_pattern() {
+        let first = regex(r"^cache_test_\d+?$").unwrap();
+        let second = regex(r"^cache_test_\d+?$").unwrap();
+        let unanchored = regex(r"cache_test_\d+?").unwrap();
+
+        assert!(std::sync::Arc::ptr_eq(&first, &second));
+        assert!(!std::sync::Arc::ptr_eq(&first, &unanchored));
+        assert!(!first.is_match("a_cache_test_1"));
+        assert!(unanchored.is_match("a_cache_test_1"));
+    }
+
+    #[test]
+    fn test_regex_with_size_limit() {
+        let first = regex_with_size_limit(r"^cache_test_size_\d+?$", 1 << 20)
+            .unwrap()
+            .unwrap();
+        let second = regex_with_size_limit(r"^cache_test_size_\d+?$", 1 << 20)
+            .unwrap()
+            .unwrap();
+
+        assert!(std::sync::Arc::ptr_eq(&first, &second));
+        assert!(matches!(
+            regex_with_size_limit(r"^cache_test_size_a{1000}$", 1 << 10).unwrap(),
+            Err(regex::Error::CompiledTooBig(_))
+        ));
+        assert!(regex(r"^cache_test_size_a{1000}$").is_ok());
+    }
+
+    #[test]
+    fn test_regex_failure_invalid_pattern() {
+        let err = regex("^cache_test_($").unwrap_err();
+
+        assert!(err.to_string().starts_with("Regex compile error: "));
+        assert!(regex("^cache_test_($").is_err());
+    }
 }
@@ -25,7 +25,12 @@ impl_from!(
     Error: regex::Error => "Error while creating regex.",
     Error: std::num::TryFromIntError => "Error while converting integer type.",
     Error: std::num::ParseIntError => "Error while parsing integer.",
+    Error: std::num::ParseFloatError => "Error while parsing float.",
     Error: std::io::Error => "IO Error.",
+);
+
+#[cfg(feature = "std")]
+impl_from!(
     Error: glob::GlobError => "Glob Error.",
     Error: glob::PatternError => "Glob Pattern Error.",
     Error: tokio::task::JoinError => "Task Join Error.",
@@ -39,4 +44,67 @@ impl Error {
             source: None,
         }
     }
+
+    /// Wrap the error in a new error with more context, keeping the error as the source.
+    pub(crate) fn with_context<T: Into<String>>(self, msg: T) -> Self {
+        Self {
+            msg: msg.into(),
+            source: Some(Box::new(self)),
+        }
+    }
+}
+
+impl serde::ser::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_from_glob_pattern_error() {
+        let err: Error = glob::Pattern::new("[").unwrap_err().into();
+
+        assert_eq!(err.to_string(), "Glob Pattern Error.");
+        assert_eq!(
+            std::error::Error::source(&err).map(|source| source.to_string()),
+            Some("Pattern syntax error near position 0: invalid range pattern".to_string())
+        );
+    }
+
+    #[test]
+    fn test_error_from_glob_pattern_error_in_find_paths_with_options() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(crate::PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: crate::Permission::default(),
+                owner: crate::Owner::default(),
+                path_type: crate::PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let err = crate::find_paths_with_options(
+            &config,
+            "key",
+            &std::collections::HashMap::new(),
+            &crate::FindPathsOptions {
+                ignore: vec!["[".to_string()],
+                relative: false,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Glob Pattern Error.");
+    }
 }
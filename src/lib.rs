@@ -8,6 +8,15 @@
 //! if there is a location to save published elements and workspaces for building out the elements,
 //! and a user needs to build out the "Widget" element, then the system can automatically build out
 //! the file and folder structure needed for the user to do their work.
+//!
+//! # Features
+//!
+//! - `std` (enabled by default): The parts of the crate that touch the filesystem or need an async
+//!   runtime, such as `find_paths`, `resolve_in_roots`, and `create_workspace`. Without this
+//!   feature, the crate only contains the path drawing and matching logic, such as [get_path],
+//!   [get_fields], and [get_workspace], which do not depend on the filesystem or threading.
+//! - `date`: Date resolvers, which draw and match dates with a strftime-style format, such as
+//!   `%Y_%m_%d`. See `ConfigBuilder::add_date_resolver`.
 
 #![deny(missing_docs)]
 #![deny(rustdoc::missing_crate_level_docs)]
@@ -21,9 +30,32 @@ mod workspace_resolver;
 
 pub use error::Error;
 pub use types::{
-    Config, ConfigBuilder, FieldKey, MetadataValue, Owner, PathItemArgs, PathType, PathValue,
-    Permission, ResolvedPathItem, Resolver, TemplateValue,
+    Config, ConfigBuilder, ConfigWarning, FieldKey, GetFieldsResult, MergeConflict, MetadataValue,
+    Owner, PathFields, PathItemArgs, PathSegment, PathType, PathValue, Permission,
+    ResolvedPathItem, Resolver, ResolverSpec, TargetOs, TemplateValue, WorkspaceNode,
+    WorkspaceSummary,
 };
+#[cfg(feature = "std")]
+pub use types::{CreateWorkspaceErrorMode, CreateWorkspaceOptions, FindPathsOptions};
 
-pub use path_resolver::{find_paths, get_fields, get_key, get_path};
-pub use workspace_resolver::{CreateWorkspaceIoFunction, create_workspace, get_workspace};
+#[cfg(feature = "std")]
+pub use path_resolver::{
+    find_latest, find_paths, find_paths_async, find_paths_from, find_paths_glob,
+    find_paths_with_fields, find_paths_with_options, find_paths_with_options_async,
+    resolve_in_roots,
+};
+pub use path_resolver::{
+    get_fields, get_fields_explain, get_fields_segments, get_fields_str, get_key, get_keys,
+    get_path, get_path_from_template_fields, get_path_upto, get_path_with_profile, translate,
+};
+#[cfg(feature = "std")]
+pub use workspace_resolver::{
+    CreateWorkspaceIoFunction, WriteStatus, create_path, create_workspace,
+    create_workspace_with_options, write_if_changed,
+};
+#[cfg(all(unix, feature = "std"))]
+pub use workspace_resolver::{PermissionMismatch, audit_workspace};
+pub use workspace_resolver::{
+    get_workspace, get_workspace_tree, get_workspace_with_profile, group_by_owner, plan_workspace,
+    sort_workspace_posix,
+};
@@ -1,6 +1,8 @@
 /// Resolve a path from a key and fields.
 ///
 /// This will get a path to find in the filesystem or save to based on the input key and fields.
+/// The config's field defaults are used for the fields that are not supplied. See
+/// [ConfigBuilder::set_field_default](crate::ConfigBuilder::set_field_default).
 ///
 /// # Errors
 ///
@@ -12,7 +14,7 @@
 /// # Example
 ///
 /// ```rust
-/// # use openpathresolver::{ConfigBuilder, get_path, Owner, PathItemArgs, PathType, Permission};
+/// # use openpathresolver::{ConfigBuilder, get_path, Owner, PathFields, PathItemArgs, PathType, Permission};
 /// let config = ConfigBuilder::new()
 ///     .add_path_item(PathItemArgs {
 ///         key: "key".try_into().unwrap(),
@@ -22,18 +24,16 @@
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
 ///     .build()
 ///     .unwrap();
 ///
-/// let fields = {
-///     let mut fields = std::collections::HashMap::new();
-///     fields.insert("thing".try_into().unwrap(), "value".into());
-///
-///     fields
-/// };
+/// let mut fields = PathFields::new();
+/// fields.insert("thing", "value").unwrap();
 ///
 /// let path = get_path(&config, "key", &fields).unwrap();
 ///
@@ -54,11 +54,19 @@ pub fn get_path(
         }
     };
 
+    let fields = config.with_field_defaults(fields);
     let mut path = std::path::PathBuf::new();
     let mut path_part = String::new();
 
     for part in item.iter() {
-        part.path.draw(&mut path_part, fields, &config.resolvers)?;
+        part.path.draw(&mut path_part, &fields, &config.resolvers)?;
+
+        if let Some(target_os) = config.target_os
+            && part.path.has_variable_tokens()
+        {
+            validate_target_os_names(target_os, &path_part, path.as_os_str().is_empty())?;
+        }
+
         path.push(path_part.as_str());
         path_part.clear();
     }
@@ -66,16 +74,131 @@ pub fn get_path(
     Ok(path)
 }
 
-/// Try to extract the fields from a key and path.
+/// Validate the names in a drawn path part for the target OS.
+///
+/// A field may contain separators, such as a `{root}` of `C:\proj`, so each name is validated on
+/// its own, and a leading Windows drive is skipped.
+fn validate_target_os_names(
+    target_os: crate::TargetOs,
+    path_part: &str,
+    is_start: bool,
+) -> Result<(), crate::Error> {
+    for (index, name) in path_part.split(['/', '\\']).enumerate() {
+        let is_drive = is_start
+            && index == 0
+            && target_os == crate::TargetOs::Windows
+            && matches!(name.as_bytes(), [drive, b':'] if drive.is_ascii_alphabetic());
+
+        if !name.is_empty() && !is_drive {
+            target_os.validate_name(name)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Resolve a path from a key and fields, seeded with a profile's default fields.
+///
+/// This is the same as [get_path], but the fields from the profile are used for any field that is
+/// not in the input fields. See [Config::profile_fields](crate::Config::profile_fields).
+///
+/// # Errors
+///
+/// - The profile, if given, must be in the config.
+/// - The same errors as [get_path].
+pub fn get_path_with_profile(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+    profile: Option<&str>,
+) -> Result<std::path::PathBuf, crate::Error> {
+    get_path(config, key, &config.profile_fields(profile, fields)?)
+}
+
+/// Resolve a path from a key and fields, only up to and including one of its ancestors.
+///
+/// This is useful for breadcrumbs, where each ancestor of a path is drawn on its own. For example,
+/// if the `shot` key is `{sequence}/{shot}` and its parent is the `sequence` key, then drawing
+/// `shot` up to `sequence` only draws the `{sequence}` part.
+///
+/// # Errors
+///
+/// - The leaf and ancestor keys need to be in the input config struct.
+/// - The ancestor key needs to be in the leaf key's chain of parents, or be the leaf key itself.
+/// - The path variables up to the ancestor need to be a subset of the fields.
+pub fn get_path_upto(
+    config: &crate::Config,
+    leaf_key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    ancestor_key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+) -> Result<std::path::PathBuf, crate::Error> {
+    let leaf_key = leaf_key.try_into()?;
+    let ancestor_key = ancestor_key.try_into()?;
+    let leaf_item = match config.get_item(&leaf_key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find path from key: {leaf_key}"
+            )));
+        }
+    };
+    let ancestor_item = match config.get_item(&ancestor_key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find path from key: {ancestor_key}"
+            )));
+        }
+    };
+
+    if ancestor_item.len() > leaf_item.len()
+        || !ancestor_item
+            .iter()
+            .zip(leaf_item.iter())
+            .all(|(ancestor_part, leaf_part)| std::ptr::eq(*ancestor_part, *leaf_part))
+    {
+        return Err(crate::Error::new(format!(
+            "'{ancestor_key}' is not an ancestor of '{leaf_key}'"
+        )));
+    }
+
+    let fields = config.with_field_defaults(fields);
+    let mut path = std::path::PathBuf::new();
+    let mut path_part = String::new();
+
+    for part in ancestor_item.iter() {
+        part.path.draw(&mut path_part, &fields, &config.resolvers)?;
+
+        if let Some(target_os) = config.target_os
+            && part.path.has_variable_tokens()
+        {
+            validate_target_os_names(target_os, &path_part, path.as_os_str().is_empty())?;
+        }
+        path.push(path_part.as_str());
+        path_part.clear();
+    }
+
+    Ok(path)
+}
+
+/// Resolve a path from a key and template fields.
+///
+/// This is the same as [get_path], but accepts the template fields so callers that keep a single
+/// field map for both path resolution and template rendering do not need to convert it first.
+/// Integer, float, and string template values are converted into path values, and the path is
+/// resolved with those.
 ///
 /// # Errors
 ///
 /// - The key needs to be in the input config struct.
+/// - The path variables need to be a subset of the fields.
+/// - Template values that are used as path values must be integers that fit into a path integer,
+///   floats, or strings. `None`, booleans, arrays, and objects are not valid path values.
 ///
 /// # Example
 ///
 /// ```rust
-/// # use openpathresolver::{ConfigBuilder, get_fields, Owner, PathItemArgs, PathType, Permission};
+/// # use openpathresolver::{ConfigBuilder, get_path_from_template_fields, Owner, PathItemArgs, PathType, Permission, TemplateValue};
 /// let config = ConfigBuilder::new()
 ///     .add_path_item(PathItemArgs {
 ///         key: "key".try_into().unwrap(),
@@ -85,95 +208,93 @@ pub fn get_path(
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
 ///     .build()
 ///     .unwrap();
 ///
-/// let path = std::path::PathBuf::from("/path/to/value");
-/// let fields = get_fields(&config, "key", &path).unwrap();
-///
-/// let expected_fields = {
+/// let fields = {
 ///     let mut fields = std::collections::HashMap::new();
 ///     fields.insert("thing".try_into().unwrap(), "value".into());
+///     fields.insert("description".try_into().unwrap(), TemplateValue::Float(1.5));
 ///
-///     Some(fields)
+///     fields
 /// };
 ///
-/// assert_eq!(fields, expected_fields);
+/// let path = get_path_from_template_fields(&config, "key", &fields).unwrap();
+///
+/// assert_eq!(path, std::path::PathBuf::from("/path/to/value"));
 /// ```
-pub fn get_fields(
+pub fn get_path_from_template_fields(
     config: &crate::Config,
     key: impl TryInto<crate::FieldKey, Error = crate::Error>,
-    path: impl AsRef<std::path::Path>,
-) -> Result<Option<crate::types::PathAttributes>, crate::Error> {
+    fields: &crate::types::TemplateAttributes,
+) -> Result<std::path::PathBuf, crate::Error> {
     let key = key.try_into()?;
-    let path = std::path::PathBuf::from(
-        path.as_ref()
-            .to_string_lossy()
-            .replace("\\", "/")
-            .replace("/", std::path::MAIN_SEPARATOR_STR),
-    );
     let item = match config.get_item(&key) {
         Some(item) => item,
         None => {
             return Err(crate::Error::new(format!(
-                "Could not find fields from key: {key}"
+                "Could not find path from key: {key}"
             )));
         }
     };
-    let mut part_pattern = String::new();
-    let mut fields = crate::types::PathAttributes::new();
-
-    for (part, path_part) in item.iter().zip(path.iter()) {
-        part_pattern.clear();
-        part_pattern.push('^');
-        part.path
-            .draw_regex_pattern(&mut part_pattern, &config.resolvers)?;
-        part_pattern.push('$');
-        let regex_pattern = crate::cache::regex(&part_pattern)?;
-        let path_part_str = path_part.to_string_lossy();
-        let captures = match regex_pattern.captures(&path_part_str) {
-            Some(captures) => captures,
-            None => return Ok(None),
-        };
-
-        let mut counter = 1;
+    let mut path_fields = crate::types::PathAttributes::new();
 
+    // Only convert the fields the path uses, so unrelated template values such as floats or
+    // objects don't cause an error.
+    for part in item.iter() {
         for token in part.path.tokens.iter() {
-            if let crate::types::Token::Variable(key) = token {
-                let captured = &captures[counter];
-                let resolver = match config.resolvers.get(key) {
-                    Some(resolver) => resolver,
-                    None => &crate::Resolver::Default,
-                };
-                let value = resolver.to_path_value(captured)?;
-                fields.insert(key.to_owned(), value);
-
-                counter += 1;
+            if let crate::types::Token::Variable { key: variable, .. }
+            | crate::types::Token::OptionalExtension { key: variable } = token
+                && let Some(value) = fields.get(variable)
+            {
+                path_fields.insert(
+                    variable.to_owned(),
+                    value.to_path_value_for(Some(variable))?,
+                );
             }
         }
     }
 
-    Ok(Some(fields))
+    get_path(config, &key, &path_fields)
 }
 
-/// Find a key from a path and fields.
+/// The field that [resolve_in_roots] binds each candidate root to.
+#[cfg(feature = "std")]
+pub(crate) const ROOT_FIELD: &str = "root";
+
+/// Resolve a path from a key and fields against multiple candidate roots.
+///
+/// This is useful when a project is mirrored across several mounts (for example, `/mnt/fast` and
+/// `/mnt/archive`) and the caller needs to know which of the mounts actually contains the path.
+/// Each root is bound to the `root` field in order, so the root most path item is expected to be
+/// defined as the `{root}` placeholder (for example, `{root}/project/{thing}`). The first resolved
+/// path that exists is returned, or `None` if the path does not exist under any of the roots.
+///
+/// # Errors
+///
+/// - The key needs to be in the input config struct.
+/// - The path variables, except for `root`, need to be a subset of the fields.
 ///
 /// # Example
 ///
 /// ```rust
-/// # use openpathresolver::{ConfigBuilder, get_key, Owner, PathItemArgs, PathType, Permission};
+/// # use openpathresolver::{ConfigBuilder, resolve_in_roots, Owner, PathItemArgs, PathType, Permission};
 /// let config = ConfigBuilder::new()
 ///     .add_path_item(PathItemArgs {
 ///         key: "key".try_into().unwrap(),
-///         path: "/path/to/{thing}".into(),
+///         path: "{root}/path/to/{thing}".into(),
 ///         parent: None,
 ///         permission: Permission::default(),
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
@@ -187,42 +308,48 @@ pub fn get_fields(
 ///     fields
 /// };
 ///
-/// let path = std::path::PathBuf::from("/path/to/value");
-/// let key = get_key(&config, &path, &fields).unwrap();
-///
-/// assert_eq!(key.map(|k| k.as_str()), Some("key"));
+/// let roots = [std::path::PathBuf::from("/mnt/fast"), std::path::PathBuf::from("/mnt/archive")];
+/// let path = resolve_in_roots(&config, "key", &fields, &roots).unwrap();
 /// ```
-pub fn get_key<'a>(
-    config: &'a crate::Config,
-    path: impl AsRef<std::path::Path>,
+#[cfg(feature = "std")]
+pub fn resolve_in_roots(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
     fields: &crate::types::PathAttributes,
-) -> Result<Option<&'a crate::FieldKey>, crate::Error> {
-    let path = path.as_ref();
+    roots: &[std::path::PathBuf],
+) -> Result<Option<std::path::PathBuf>, crate::Error> {
+    let key = key.try_into()?;
+    let root_key = crate::FieldKey::new(ROOT_FIELD)?;
+    let mut fields = fields.clone();
 
-    for (key, _) in config.item_map.iter() {
-        let other_path = get_path(config, key, fields)?;
+    for root in roots {
+        fields.insert(
+            root_key.clone(),
+            crate::PathValue::String(root.to_string_lossy().into_owned()),
+        );
+        let path = get_path(config, &key, &fields)?;
 
-        if path == other_path {
-            return Ok(Some(key));
+        if path.exists() {
+            return Ok(Some(path));
         }
     }
 
     Ok(None)
 }
 
-/// Find paths from a given key and fields.
+/// Try to extract the fields from a key and path.
 ///
-/// This differs from the [get_path] because it will search the filesystem for the paths and the
-/// fields do not need to be a superset of the path variables. If a path variable is missing, then
-/// that will signify to the system to return all paths that match that variable's shape. For
-/// example, if a user needs to find all of the versions of the "Widget" publish, and the structure
-/// of the path looks like `"{root}/publishes/{entity}/{version}"`, then the only required fields
-/// will be `root` and `entity`.
+/// Both `/` and `\\` are treated as separators, and repeated separators are collapsed, so
+/// `/path//to/value` matches the same key as `/path/to/value`.
+///
+/// # Errors
+///
+/// - The key needs to be in the input config struct.
 ///
 /// # Example
 ///
 /// ```rust
-/// # use openpathresolver::{ConfigBuilder, find_paths, Owner, PathItemArgs, PathType, Permission};
+/// # use openpathresolver::{ConfigBuilder, get_fields, Owner, PathItemArgs, PathType, Permission};
 /// let config = ConfigBuilder::new()
 ///     .add_path_item(PathItemArgs {
 ///         key: "key".try_into().unwrap(),
@@ -232,207 +359,4276 @@ pub fn get_key<'a>(
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
 ///     .build()
 ///     .unwrap();
 ///
-/// let fields = {
+/// let path = std::path::PathBuf::from("/path/to/value");
+/// let fields = get_fields(&config, "key", &path).unwrap();
+///
+/// let expected_fields = {
 ///     let mut fields = std::collections::HashMap::new();
 ///     fields.insert("thing".try_into().unwrap(), "value".into());
 ///
-///     fields
+///     Some(fields)
 /// };
 ///
-/// find_paths(&config, "key", &fields);
+/// assert_eq!(fields, expected_fields);
 /// ```
-pub fn find_paths(
+pub fn get_fields(
     config: &crate::Config,
     key: impl TryInto<crate::FieldKey, Error = crate::Error>,
-    fields: &crate::types::PathAttributes,
-) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    path: impl AsRef<std::path::Path>,
+) -> Result<Option<crate::types::PathAttributes>, crate::Error> {
     let key = key.try_into()?;
+    let path = to_native_separators(path.as_ref());
     let item = match config.get_item(&key) {
         Some(item) => item,
         None => {
             return Err(crate::Error::new(format!(
-                "Could not find paths from key: {key}"
+                "Could not find fields from key: {key}"
             )));
         }
     };
+    match_fields(config, &item, split_path(&path))
+}
 
-    let mut regex_pattern = String::new();
-    let mut glob_path = std::path::PathBuf::new();
-
-    regex_pattern.push('^');
-
-    for (index, part) in item.iter().enumerate() {
-        let mut regex_part = String::new();
-        part.path
-            .draw_regex_pattern(&mut regex_part, &config.resolvers)?;
-
-        let value = if part.path.has_variable_tokens() {
-            part.path.try_to_literal_token(fields, &config.resolvers)?
-        } else {
-            part.path.clone()
-        };
-
-        let mut glob_part = String::new();
-        value.draw_glob_pattern(&mut glob_part)?;
-
-        regex_pattern.push_str(&regex_part);
-
-        if index != item.len() - 1 && !regex_pattern.ends_with(r"[\\/]") {
-            regex_pattern.push_str(r"[\\/]");
+/// Try to extract the fields from a key and path, and explain why the path does not match.
+///
+/// This is the same as [get_fields], but when the path does not match, the first path component
+/// that did not match is returned with the regex that it was expected to match. This is useful
+/// for finding out why a path is not classified as the key.
+///
+/// # Errors
+///
+/// - The same errors as [get_fields].
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, get_fields_explain, GetFieldsResult, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let result = get_fields_explain(&config, "key", "/path/from/value").unwrap();
+///
+/// assert_eq!(
+///     result,
+///     GetFieldsResult::Mismatch {
+///         index: 2,
+///         pattern: "^to$".into(),
+///         segment: "from".into(),
+///     }
+/// );
+/// ```
+pub fn get_fields_explain(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    path: impl AsRef<std::path::Path>,
+) -> Result<crate::GetFieldsResult, crate::Error> {
+    let key = key.try_into()?;
+    let path = to_native_separators(path.as_ref());
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find fields from key: {key}"
+            )));
         }
+    };
+    explain_fields(config, &item, split_path(&path))
+}
 
-        glob_path.push(glob_part);
-    }
+/// Replace both `/` and `\\` with the OS separator.
+fn to_native_separators(path: &std::path::Path) -> std::path::PathBuf {
+    std::path::PathBuf::from(
+        path.to_string_lossy()
+            .replace("\\", "/")
+            .replace("/", std::path::MAIN_SEPARATOR_STR),
+    )
+}
 
-    regex_pattern.push('$');
+/// Get fields from a path string split by an explicit separator.
+///
+/// This is useful for paths that come from a cross-platform source, such as a database that always
+/// stores paths with forward slashes. Unlike [get_fields], the input is not split by the OS
+/// separator, so a forward slash path can be classified on Windows and vice versa.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, get_fields_str, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let fields = get_fields_str(&config, "key", "/path/to/value", '/').unwrap();
+///
+/// let expected_fields = {
+///     let mut fields = std::collections::HashMap::new();
+///     fields.insert("thing".try_into().unwrap(), "value".into());
+///
+///     Some(fields)
+/// };
+///
+/// assert_eq!(fields, expected_fields);
+/// ```
+pub fn get_fields_str(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    path: &str,
+    separator: char,
+) -> Result<Option<crate::types::PathAttributes>, crate::Error> {
+    let key = key.try_into()?;
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find fields from key: {key}"
+            )));
+        }
+    };
 
-    let compiled_regex = regex::Regex::new(&regex_pattern)?;
-    let mut out_paths = Vec::new();
+    match_fields(config, &item, split_path_str(path, separator))
+}
 
-    for result in glob::glob(glob_path.to_string_lossy().as_ref())? {
+/// Get the literal and field segments of a path, in the order that they appear in the path.
+///
+/// This is the same as [get_fields], but also keeps the literal text between the fields, so the
+/// path can be rebuilt or re-templated. Adjacent literals, including the path separators, are
+/// merged into a single segment.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, get_fields_segments, Owner, PathItemArgs, PathSegment, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "path/to/{thing}.txt".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let segments = get_fields_segments(&config, "key", "path/to/value.txt").unwrap();
+/// let separator = std::path::MAIN_SEPARATOR;
+///
+/// assert_eq!(
+///     segments,
+///     Some(vec![
+///         PathSegment::Literal(format!("path{separator}to{separator}")),
+///         PathSegment::Field("thing".try_into().unwrap(), "value".into()),
+///         PathSegment::Literal(".txt".into()),
+///     ])
+/// );
+/// ```
+pub fn get_fields_segments(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    path: impl AsRef<std::path::Path>,
+) -> Result<Option<Vec<crate::PathSegment>>, crate::Error> {
+    let key = key.try_into()?;
+    let fields = match get_fields(config, &key, &path)? {
+        Some(fields) => fields,
+        None => return Ok(None),
+    };
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find fields from key: {key}"
+            )));
+        }
+    };
+
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+
+    for (index, part) in item.iter().enumerate() {
+        if index != 0 && !literal.ends_with(['/', '\\']) {
+            literal.push(std::path::MAIN_SEPARATOR);
+        }
+
+        for token in part.path.tokens.iter() {
+            match token {
+                crate::types::Token::Literal(text) => literal.push_str(text),
+                crate::types::Token::CatchAll => {
+                    // The catch-all keeps the rest of the path as a literal.
+                    let path = to_native_separators(path.as_ref());
+                    let rest = split_path(&path).split_off(index);
+                    literal.push_str(&rest.join(std::path::MAIN_SEPARATOR_STR));
+                }
+                crate::types::Token::Variable { key, .. }
+                | crate::types::Token::OptionalExtension { key } => {
+                    if matches!(token, crate::types::Token::OptionalExtension { .. }) {
+                        // A missing optional extension has no segments, not even its `.`.
+                        if !fields.contains_key(key) {
+                            continue;
+                        }
+
+                        literal.push('.');
+                    }
+
+                    if !literal.is_empty() {
+                        segments.push(crate::PathSegment::Literal(std::mem::take(&mut literal)));
+                    }
+
+                    // The fields are already checked, so a repeated field has the same value everywhere.
+                    let value = match fields.get(key) {
+                        Some(value) => value.clone(),
+                        None => continue,
+                    };
+                    segments.push(crate::PathSegment::Field(key.to_owned(), value));
+                }
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(crate::PathSegment::Literal(literal));
+    }
+
+    Ok(Some(segments))
+}
+
+/// Split a path into the same parts as the config's path items.
+///
+/// The config keeps a prefix such as `C:` or the verbatim `\\?\C:` together with the root
+/// separator as a single part, while [std::path::Path::iter] returns them as separate parts.
+fn split_path(path: &std::path::Path) -> Vec<std::borrow::Cow<'_, str>> {
+    let mut parts = Vec::new();
+    let mut components = path.components().peekable();
+
+    if let Some(std::path::Component::Prefix(prefix)) = components.peek() {
+        let prefix = prefix.as_os_str().to_string_lossy();
+        components.next();
+
+        if components
+            .next_if_eq(&std::path::Component::RootDir)
+            .is_some()
+        {
+            parts.push(format!("{prefix}{}", std::path::MAIN_SEPARATOR).into());
+        } else {
+            parts.push(prefix);
+        }
+    }
+
+    parts.extend(components.map(|component| component.as_os_str().to_string_lossy()));
+
+    parts
+}
+
+/// Split a path string into the same parts that [std::path::Path::iter] would produce.
+///
+/// A leading separator becomes a root part, and a drive such as `C:` keeps its trailing separator.
+fn split_path_str(path: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut segments = path.split(separator).peekable();
+
+    if path.starts_with(separator) {
+        parts.push("/".to_string());
+    } else if let Some(segment) = segments.next_if(|segment| segment.ends_with(':')) {
+        parts.push(format!("{segment}/"));
+    }
+
+    parts.extend(
+        segments
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_string()),
+    );
+
+    parts
+}
+
+fn match_fields(
+    config: &crate::Config,
+    item: &[&crate::types::PathItem],
+    path_parts: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<Option<crate::types::PathAttributes>, crate::Error> {
+    match explain_fields(config, item, path_parts)? {
+        crate::GetFieldsResult::Match(fields) => Ok(Some(fields)),
+        crate::GetFieldsResult::Mismatch { .. } => Ok(None),
+    }
+}
+
+fn explain_fields(
+    config: &crate::Config,
+    item: &[&crate::types::PathItem],
+    path_parts: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<crate::GetFieldsResult, crate::Error> {
+    let mut part_pattern = String::new();
+    let mut fields = crate::types::PathAttributes::new();
+    let mut conflicts: Vec<(crate::FieldKey, Vec<crate::PathValue>)> = Vec::new();
+
+    for (index, (part, path_part)) in item.iter().zip(path_parts).enumerate() {
+        part_pattern.clear();
+        part_pattern.push('^');
+        part_pattern.push_str(&part.pattern);
+        part_pattern.push('$');
+        let regex_pattern = crate::cache::regex(&part_pattern)?;
+        let captures = match regex_pattern.captures(path_part.as_ref()) {
+            Some(captures) => captures,
+            None => {
+                return Ok(crate::GetFieldsResult::Mismatch {
+                    index,
+                    pattern: part_pattern,
+                    segment: path_part.as_ref().to_string(),
+                });
+            }
+        };
+
+        let values = match capture_values(&config.resolvers, &part.path, &captures)? {
+            Some(values) => values,
+            None => {
+                return Ok(crate::GetFieldsResult::Mismatch {
+                    index,
+                    pattern: part_pattern,
+                    segment: path_part.as_ref().to_string(),
+                });
+            }
+        };
+
+        for (key, value) in values {
+            // Keep every distinct value of a repeated field, so the error shows the full
+            // conflict rather than the first mismatch.
+            match fields.get(key) {
+                Some(existing) if *existing != value => {
+                    match conflicts.iter_mut().find(|(other_key, _)| other_key == key) {
+                        Some((_, values)) => {
+                            if !values.contains(&value) {
+                                values.push(value);
+                            }
+                        }
+                        None => conflicts.push((key.to_owned(), vec![existing.clone(), value])),
+                    }
+                }
+                Some(_) => (),
+                None => {
+                    fields.insert(key.to_owned(), value);
+                }
+            }
+        }
+    }
+
+    if !conflicts.is_empty() {
+        let conflicts = conflicts
+            .iter()
+            .map(|(key, values)| format!("{:?} has the values {values:?}", key.as_str()))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        return Err(crate::Error::new(format!(
+            "Mismatched field values: {conflicts}"
+        )));
+    }
+
+    Ok(crate::GetFieldsResult::Match(fields))
+}
+
+/// Parse the values that a path component's pattern captured, in the order of the tokens.
+///
+/// A value outside of its resolver's range, such as a version of 0 when the versions start at 1,
+/// does not match the component, so `None` is returned instead of an error.
+fn capture_values<'a>(
+    resolvers: &crate::types::Resolvers,
+    tokens: &'a crate::types::Tokens,
+    captures: &regex::Captures,
+) -> Result<Option<Vec<(&'a crate::FieldKey, crate::PathValue)>>, crate::Error> {
+    let mut values = Vec::new();
+    let mut counter = 1;
+
+    for token in tokens.tokens.iter() {
+        let (key, optional) = match token {
+            crate::types::Token::Literal(_) | crate::types::Token::CatchAll => continue,
+            crate::types::Token::Variable { key, .. } => (key, false),
+            crate::types::Token::OptionalExtension { key } => (key, true),
+        };
+        let captured = captures.get(counter);
+        counter += 1;
+
+        // An optional extension that is missing from the path does not capture anything.
+        let captured = match captured {
+            Some(captured) => captured.as_str(),
+            None if optional => continue,
+            None => {
+                return Err(crate::Error::new(format!(
+                    "Could not capture {:?} from the path.",
+                    key.as_str()
+                )));
+            }
+        };
+        let resolver = match resolvers.get(key) {
+            Some(resolver) => resolver,
+            None => &crate::Resolver::Default,
+        };
+        let value = match resolver.parse_value(captured) {
+            Ok(value) => value,
+            Err(error) => {
+                let msg = format!("Invalid value {captured:?} for {:?}: {error}", key.as_str());
+
+                return Err(error.with_context(msg));
+            }
+        };
+
+        if !resolver.is_in_range(&value) {
+            return Ok(None);
+        }
+
+        values.push((key, value));
+    }
+
+    Ok(Some(values))
+}
+
+/// Find a key from a path and fields.
+///
+/// If more than one key resolves to the path, then only the first one that is found is returned.
+/// Use [get_keys] to find all of them.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, get_key, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let fields = {
+///     let mut fields = std::collections::HashMap::new();
+///     fields.insert("thing".try_into().unwrap(), "value".into());
+///
+///     fields
+/// };
+///
+/// let path = std::path::PathBuf::from("/path/to/value");
+/// let key = get_key(&config, &path, &fields).unwrap();
+///
+/// assert_eq!(key.map(|k| k.as_str()), Some("key"));
+/// ```
+pub fn get_key<'a>(
+    config: &'a crate::Config,
+    path: impl AsRef<std::path::Path>,
+    fields: &crate::types::PathAttributes,
+) -> Result<Option<&'a crate::FieldKey>, crate::Error> {
+    let path = path.as_ref();
+
+    for (key, _) in config.item_map.iter() {
+        let other_path = get_path(config, key, fields)?;
+
+        if path == other_path {
+            return Ok(Some(key));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Find every key from a path and fields.
+///
+/// This is the same as [get_key], but every key that resolves to the path is returned instead of
+/// the first one, such as when a file and its alias resolve to the same path. The keys are sorted,
+/// so the order is the same between runs.
+///
+/// # Errors
+///
+/// - The same errors as [get_key].
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, get_keys, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .add_path_item(PathItemArgs {
+///         key: "alias".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let fields = {
+///     let mut fields = std::collections::HashMap::new();
+///     fields.insert("thing".try_into().unwrap(), "value".into());
+///
+///     fields
+/// };
+///
+/// let keys = get_keys(&config, "/path/to/value", &fields).unwrap();
+///
+/// assert_eq!(keys.iter().map(|k| k.as_str()).collect::<Vec<_>>(), vec!["alias", "key"]);
+/// ```
+pub fn get_keys<'a>(
+    config: &'a crate::Config,
+    path: impl AsRef<std::path::Path>,
+    fields: &crate::types::PathAttributes,
+) -> Result<Vec<&'a crate::FieldKey>, crate::Error> {
+    let path = path.as_ref();
+    let mut keys = Vec::new();
+
+    for (key, _) in config.item_map.iter() {
+        let other_path = get_path(config, key, fields)?;
+
+        if path == other_path {
+            keys.push(key);
+        }
+    }
+
+    keys.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+    Ok(keys)
+}
+
+/// Translate a path from one config's key to the equivalent path of another config's key.
+///
+/// The fields are extracted from the path with [get_fields] on the first config, and then the new
+/// path is resolved from those fields with [get_path] on the second config. This is useful when
+/// migrating from an old layout to a new one, as long as the fields have the same names in both
+/// configs. If the path does not match the first key, then `None` is returned.
+///
+/// # Errors
+///
+/// - Both keys need to be in their configs.
+/// - The extracted fields must resolve the second key.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, translate, Owner, PathItemArgs, PathType, Permission};
+/// let old_config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/old/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+/// let new_config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/new/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let path = translate(&old_config, "key", "/old/value", &new_config, "key").unwrap();
+///
+/// assert_eq!(path, Some(std::path::PathBuf::from("/new/value")));
+/// ```
+pub fn translate(
+    from_config: &crate::Config,
+    from_key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    path: impl AsRef<std::path::Path>,
+    to_config: &crate::Config,
+    to_key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+) -> Result<Option<std::path::PathBuf>, crate::Error> {
+    let fields = match get_fields(from_config, from_key, path)? {
+        Some(fields) => fields,
+        None => return Ok(None),
+    };
+
+    get_path(to_config, to_key, &fields).map(Some)
+}
+
+/// Find paths from a given key and fields.
+///
+/// This differs from the [get_path] because it will search the filesystem for the paths and the
+/// fields do not need to be a superset of the path variables. If a path variable is missing, then
+/// that will signify to the system to return all paths that match that variable's shape. For
+/// example, if a user needs to find all of the versions of the "Widget" publish, and the structure
+/// of the path looks like `"{root}/publishes/{entity}/{version}"`, then the only required fields
+/// will be `root` and `entity`.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, find_paths, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let fields = {
+///     let mut fields = std::collections::HashMap::new();
+///     fields.insert("thing".try_into().unwrap(), "value".into());
+///
+///     fields
+/// };
+///
+/// find_paths(&config, "key", &fields);
+/// ```
+#[cfg(feature = "std")]
+pub fn find_paths(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    find_paths_with_options(config, key, fields, &crate::FindPathsOptions::default())
+}
+
+/// Find paths from a given key and fields, paired with the fields of each path.
+///
+/// This is the same as [find_paths], but the values that each path component captured while
+/// searching are merged with the input fields. For example, if the `{frame}` field is not in the
+/// input fields, then each path is paired with the frame that it was found with. The captured
+/// values are parsed by their resolvers, so an integer field is a
+/// [PathValue::Integer](crate::PathValue::Integer). A field that is repeated in the path must
+/// have the same value everywhere, so a path with different values is skipped.
+///
+/// # Errors
+///
+/// - The same errors as [find_paths].
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, find_paths_with_fields, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_integer_resolver("frame", 4)
+///     .unwrap()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/render.{frame}.exr".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::File,
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// for (path, fields) in find_paths_with_fields(&config, "key", &std::collections::HashMap::new()).unwrap() {
+///     println!("{path:?}: {:?}", fields.get(&"frame".try_into().unwrap()));
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn find_paths_with_fields(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+) -> Result<Vec<(std::path::PathBuf, crate::types::PathAttributes)>, crate::Error> {
+    let key = key.try_into()?;
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find paths from key: {key}"
+            )));
+        }
+    };
+
+    let mut found = Vec::new();
+    recursive_find_paths(
+        config,
+        &item,
+        fields,
+        &[],
+        std::path::PathBuf::new(),
+        &mut found,
+    )?;
+    found.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    Ok(found)
+}
+
+/// Find paths from a given key and fields, with extra options for the search.
+///
+/// This is the same as [find_paths], but the search can be controlled with the
+/// [FindPathsOptions](crate::FindPathsOptions). For example, the ignore patterns will skip any
+/// files or directories that match while walking the filesystem, so large directories such as
+/// `__pycache__` are never searched.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, find_paths_with_options, FindPathsOptions, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let options = FindPathsOptions {
+///     ignore: vec!["__pycache__".to_string(), "*.tmp".to_string()],
+///     relative: false,
+/// };
+///
+/// find_paths_with_options(&config, "key", &std::collections::HashMap::new(), &options);
+/// ```
+#[cfg(feature = "std")]
+pub fn find_paths_with_options(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+    options: &crate::FindPathsOptions,
+) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    let key = key.try_into()?;
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find paths from key: {key}"
+            )));
+        }
+    };
+
+    let mut ignore = Vec::with_capacity(options.ignore.len());
+
+    for pattern in options.ignore.iter() {
+        ignore.push(glob::Pattern::new(pattern)?);
+    }
+
+    let mut found = Vec::new();
+    recursive_find_paths(
+        config,
+        &item,
+        fields,
+        &ignore,
+        std::path::PathBuf::new(),
+        &mut found,
+    )?;
+    let mut out_paths = found.into_iter().map(|(path, _)| path).collect::<Vec<_>>();
+
+    if options.relative {
+        out_paths = relative_paths(config, &key, out_paths);
+    }
+
+    out_paths.sort();
+
+    Ok(out_paths)
+}
+
+/// Strip the components that matched the key's root item from each path, for
+/// [FindPathsOptions::relative](crate::FindPathsOptions::relative).
+#[cfg(feature = "std")]
+fn relative_paths(
+    config: &crate::Config,
+    key: &crate::FieldKey,
+    paths: Vec<std::path::PathBuf>,
+) -> Vec<std::path::PathBuf> {
+    let root_len = root_item_len(config, key);
+
+    paths
+        .into_iter()
+        .map(|path| {
+            path.components()
+                .filter(|component| !is_anchor(component))
+                .skip(root_len)
+                .collect()
+        })
+        .collect()
+}
+
+/// Find paths from a given key and fields, starting the search in a known directory.
+///
+/// This is the same as [find_paths], but the start directory takes the place of the key's root
+/// item, which is the key's top-most parent, such as `{root}`. The root item and its ancestors
+/// are not resolved, so their fields do not need to be in the fields map, and the search begins
+/// at the start directory with the first path component below the root item. A key without a
+/// parent does not have a root item, so the whole path without its anchor, such as `/` or `C:\`,
+/// is searched from the start directory.
+///
+/// # Errors
+///
+/// - The key needs to be in the input config struct.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, find_paths_from, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "root".try_into().unwrap(),
+///         path: "{root}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "path/to/{thing}".into(),
+///         parent: Some("root".try_into().unwrap()),
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let start_dir = tempfile::tempdir().unwrap();
+/// std::fs::create_dir_all(start_dir.path().join("path/to/value")).unwrap();
+///
+/// let paths = find_paths_from(&config, "key", &std::collections::HashMap::new(), start_dir.path())
+///     .unwrap();
+///
+/// assert_eq!(paths, vec![start_dir.path().join("path/to/value")]);
+/// ```
+#[cfg(feature = "std")]
+pub fn find_paths_from(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+    start_dir: impl AsRef<std::path::Path>,
+) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    let key = key.try_into()?;
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find paths from key: {key}"
+            )));
+        }
+    };
+    let root_len = match root_item_chain(config, &key).len() {
+        0 => item.iter().take_while(|part| is_anchor_item(part)).count(),
+        root_len => root_len,
+    };
+
+    let mut found = Vec::new();
+    recursive_find_paths(
+        config,
+        &item[root_len..],
+        fields,
+        &[],
+        start_dir.as_ref().to_path_buf(),
+        &mut found,
+    )?;
+    let mut out_paths = found.into_iter().map(|(path, _)| path).collect::<Vec<_>>();
+    out_paths.sort();
+
+    Ok(out_paths)
+}
+
+/// The item indexes of the key's root item and its ancestors, from the top of the path.
+///
+/// The root item is the key's top-most parent. A key without a parent does not have a root item,
+/// so the chain is empty.
+#[cfg(feature = "std")]
+fn root_item_chain<'a>(config: &'a crate::Config, key: &crate::FieldKey) -> &'a [usize] {
+    let key = match config
+        .composite_map
+        .get(key)
+        .and_then(|parts| parts.first())
+    {
+        Some(first_part) => first_part,
+        None => key,
+    };
+    let chain = match config.chain_map.get(key) {
+        Some(chain) => chain,
+        None => return &[],
+    };
+
+    match chain
+        .iter()
+        .position(|index| config.index_key_map.contains_key(index))
+    {
+        Some(position) if position + 1 < chain.len() => &chain[..position + 1],
+        _ => &[],
+    }
+}
+
+/// The number of path components in the key's root item, which is the key's top-most parent.
+///
+/// The anchor of the path, such as `/` or `C:\`, is not counted. A key without a parent does not
+/// have a root item, so the length is 0.
+#[cfg(feature = "std")]
+fn root_item_len(config: &crate::Config, key: &crate::FieldKey) -> usize {
+    root_item_chain(config, key)
+        .iter()
+        .filter(|index| !is_anchor_item(&config.items[**index]))
+        .count()
+}
+
+/// Whether the path item is only the anchor of a path, such as `/` or `C:\`.
+#[cfg(feature = "std")]
+fn is_anchor_item(item: &crate::types::PathItem) -> bool {
+    match item.path.tokens.as_slice() {
+        [crate::types::Token::Literal(literal)] => std::path::Path::new(literal)
+            .components()
+            .all(|component| is_anchor(&component)),
+        _ => false,
+    }
+}
+
+/// Find the existing paths for a key that are under a directory.
+///
+/// The directory is matched against the top of the key's path, and the search starts in the
+/// directory, so the paths outside of it are never read. The values that the directory captures
+/// must agree with the fields.
+#[cfg(feature = "std")]
+pub(crate) fn find_paths_under(
+    config: &crate::Config,
+    key: &crate::FieldKey,
+    fields: &crate::types::PathAttributes,
+    root: &std::path::Path,
+) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    let item = match config.get_item(key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find paths from key: {key}"
+            )));
+        }
+    };
+    let root_parts = split_path(root);
+
+    // A directory below a catch-all is searched by the catch-all.
+    let root_len = match item.iter().position(|part| part.path.is_catch_all()) {
+        Some(catch_all_index) => root_parts.len().min(catch_all_index),
+        None if root_parts.len() > item.len() => return Ok(Vec::new()),
+        None => root_parts.len(),
+    };
+    let mut root_fields = match match_fields(config, &item[..root_len], &root_parts[..root_len])? {
+        Some(root_fields) => root_fields,
+        None => return Ok(Vec::new()),
+    };
+
+    for (key, value) in fields.iter() {
+        match root_fields.get(key) {
+            Some(root_value) if root_value != value => return Ok(Vec::new()),
+            Some(_) => (),
+            None => {
+                root_fields.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+    recursive_find_paths(
+        config,
+        &item[root_len..],
+        &root_fields,
+        &[],
+        root.to_path_buf(),
+        &mut found,
+    )?;
+    let mut out_paths = found.into_iter().map(|(path, _)| path).collect::<Vec<_>>();
+    out_paths.sort();
+
+    Ok(out_paths)
+}
+
+#[cfg(feature = "std")]
+fn is_anchor(component: &std::path::Component) -> bool {
+    matches!(
+        component,
+        std::path::Component::Prefix(_) | std::path::Component::RootDir
+    )
+}
+
+/// Find paths from a given key and fields with a single glob.
+///
+/// This is the same as [find_paths], but the path is drawn as one glob pattern, such as
+/// `/path/to/*`, and the matches from the [glob] crate are then checked against the shape of the
+/// path. This can be simpler to reason about on small trees, but every directory that matches a
+/// wildcard is searched, so [find_paths] is usually faster on large trees.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, find_paths_glob, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// find_paths_glob(&config, "key", &std::collections::HashMap::new());
+/// ```
+#[cfg(feature = "std")]
+pub fn find_paths_glob(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    let key = key.try_into()?;
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find paths from key: {key}"
+            )));
+        }
+    };
+
+    let mut regex_pattern = String::new();
+    let mut glob_path = std::path::PathBuf::new();
+    regex_pattern.push('^');
+
+    for (index, part) in item.iter().enumerate() {
+        let value = part.path.try_to_literal_token(fields, &config.resolvers)?;
+        let mut glob_part = String::new();
+        value.draw_glob_pattern(&mut glob_part)?;
+        value.draw_regex_pattern(&mut regex_pattern, &config.resolvers)?;
+
+        if index != item.len() - 1 && !regex_pattern.ends_with(r"[\\/]") {
+            regex_pattern.push_str(r"[\\/]");
+        }
+
+        glob_path.push(glob_part);
+    }
+
+    regex_pattern.push('$');
+    let regex_pattern = crate::cache::regex(&regex_pattern)?;
+    let mut out_paths = Vec::new();
+
+    for result in glob::glob(glob_path.to_string_lossy().as_ref())? {
         let path = result?;
 
-        if compiled_regex.is_match(path.to_string_lossy().as_ref()) {
-            out_paths.push(path);
-        }
-    }
+        if regex_pattern.is_match(path.to_string_lossy().as_ref())
+            && matches!(match_fields(config, &item, split_path(&path)), Ok(Some(_)))
+        {
+            out_paths.push(path);
+        }
+    }
+
+    out_paths.sort();
+
+    Ok(out_paths)
+}
+
+/// Find paths from a given key and fields without blocking the async runtime.
+///
+/// This is the same as [find_paths], but the filesystem is read with [tokio::fs], so it can be
+/// awaited in an async service without wrapping it in a blocking task.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, find_paths_async, Owner, PathItemArgs, PathType, Permission};
+/// # #[tokio::main]
+/// # async fn main() {
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// find_paths_async(&config, "key", &std::collections::HashMap::new()).await;
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub async fn find_paths_async(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    find_paths_with_options_async(config, key, fields, &crate::FindPathsOptions::default()).await
+}
+
+/// Find paths from a given key and fields without blocking the async runtime, with extra options
+/// for the search.
+///
+/// This is the same as [find_paths_with_options], but the filesystem is read with [tokio::fs]
+/// like [find_paths_async].
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, find_paths_with_options_async, FindPathsOptions, Owner, PathItemArgs, PathType, Permission};
+/// # #[tokio::main]
+/// # async fn main() {
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let options = FindPathsOptions {
+///     ignore: vec!["__pycache__".to_string(), "*.tmp".to_string()],
+///     relative: false,
+/// };
+///
+/// find_paths_with_options_async(&config, "key", &std::collections::HashMap::new(), &options).await;
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub async fn find_paths_with_options_async(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+    options: &crate::FindPathsOptions,
+) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    let key = key.try_into()?;
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find paths from key: {key}"
+            )));
+        }
+    };
+
+    let mut ignore = Vec::with_capacity(options.ignore.len());
+
+    for pattern in options.ignore.iter() {
+        ignore.push(glob::Pattern::new(pattern)?);
+    }
+
+    let mut out_paths = Vec::new();
+    recursive_find_paths_async(
+        config,
+        &item,
+        fields,
+        &ignore,
+        std::path::PathBuf::new(),
+        &mut out_paths,
+    )
+    .await?;
+
+    if options.relative {
+        out_paths = relative_paths(config, &key, out_paths);
+    }
+
+    out_paths.sort();
+
+    Ok(out_paths)
+}
+
+/// Find the latest paths from a given key and fields.
+///
+/// The fields must resolve every placeholder of the key except for one, such as the version. The
+/// found paths are sorted by the value of that placeholder in descending order, and the first `n`
+/// paths are returned. Integers and floats are sorted numerically, and strings are sorted
+/// alphabetically.
+///
+/// # Errors
+///
+/// - The key needs to be in the input config struct.
+/// - Exactly one placeholder must be unresolved by the fields.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, find_latest, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_integer_resolver("version", 3)
+///     .unwrap()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/v{version}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// find_latest(&config, "key", &std::collections::HashMap::new(), 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn find_latest(
+    config: &crate::Config,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    fields: &crate::types::PathAttributes,
+    n: usize,
+) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+    let key = key.try_into()?;
+    let item = match config.get_item(&key) {
+        Some(item) => item,
+        None => {
+            return Err(crate::Error::new(format!(
+                "Could not find paths from key: {key}"
+            )));
+        }
+    };
+
+    let mut unresolved_keys = Vec::new();
+
+    for part in item.iter() {
+        for variable in part.path.variable_keys() {
+            if !fields.contains_key(variable) && !unresolved_keys.contains(&variable) {
+                unresolved_keys.push(variable);
+            }
+        }
+    }
+
+    let latest_key = match unresolved_keys.as_slice() {
+        [latest_key] => (*latest_key).to_owned(),
+        _ => {
+            return Err(crate::Error::new(format!(
+                "Expected exactly one unresolved field, found {:?}",
+                unresolved_keys
+                    .iter()
+                    .map(|key| key.as_str())
+                    .collect::<Vec<_>>()
+            )));
+        }
+    };
+
+    let mut found = Vec::new();
+
+    for path in find_paths(config, &key, fields)? {
+        let value = match get_fields(config, &key, &path)? {
+            Some(mut path_fields) => match path_fields.remove(&latest_key) {
+                Some(value) => value,
+                None => continue,
+            },
+            None => continue,
+        };
+        found.push((value, path));
+    }
+
+    found.sort_by(|(a, _), (b, _)| match (a, b) {
+        (crate::PathValue::Integer(a), crate::PathValue::Integer(b)) => b.cmp(a),
+        (crate::PathValue::String(a), crate::PathValue::String(b)) => b.cmp(a),
+        #[cfg(feature = "date")]
+        (crate::PathValue::Date(a), crate::PathValue::Date(b)) => b.cmp(a),
+        (_, crate::PathValue::String(_)) => std::cmp::Ordering::Greater,
+        (crate::PathValue::String(_), _) => std::cmp::Ordering::Less,
+        (a, b) => path_value_to_f64(b).total_cmp(&path_value_to_f64(a)),
+    });
+
+    Ok(found.into_iter().take(n).map(|(_, path)| path).collect())
+}
+
+/// Convert a numeric path value into a float, so integers and floats can be compared.
+#[cfg(feature = "std")]
+fn path_value_to_f64(value: &crate::PathValue) -> f64 {
+    match value {
+        crate::PathValue::Integer(value) => f64::from(*value),
+        crate::PathValue::Float(value) => *value,
+        crate::PathValue::String(_) => f64::NAN,
+        #[cfg(feature = "date")]
+        crate::PathValue::Date(_) => f64::NAN,
+    }
+}
+
+/// Walk the filesystem one path component at a time.
+///
+/// Literal components are joined without searching. Components with unresolved variables read the
+/// current directory, and only descend into the entries that match the component's pattern.
+#[cfg(feature = "std")]
+fn recursive_find_paths(
+    config: &crate::Config,
+    item: &[&crate::types::PathItem],
+    fields: &crate::types::PathAttributes,
+    ignore: &[glob::Pattern],
+    root: std::path::PathBuf,
+    found: &mut Vec<(std::path::PathBuf, crate::types::PathAttributes)>,
+) -> Result<(), crate::Error> {
+    let (part, rest) = match item.split_first() {
+        Some(value) => value,
+        None => {
+            if root.exists() {
+                found.push((root, fields.clone()));
+            }
+
+            return Ok(());
+        }
+    };
+
+    if part.path.is_catch_all() {
+        let mut out_paths = Vec::new();
+        recursive_find_all_paths(ignore, root, &mut out_paths)?;
+        found.extend(out_paths.into_iter().map(|path| (path, fields.clone())));
+
+        return Ok(());
+    }
+
+    let value = part.path.try_to_literal_token(fields, &config.resolvers)?;
+
+    if !value.has_variable_tokens() {
+        let mut literal = String::new();
+        value.draw(&mut literal, fields, &config.resolvers)?;
+
+        return recursive_find_paths(config, rest, fields, ignore, root.join(literal), found);
+    }
+
+    let mut pattern = String::new();
+    pattern.push('^');
+    value.draw_regex_pattern(&mut pattern, &config.resolvers)?;
+    pattern.push('$');
+    let pattern = crate::cache::regex(&pattern)?;
+
+    let dir = if root.as_os_str().is_empty() {
+        std::path::Path::new(".")
+    } else {
+        root.as_path()
+    };
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error)
+            if matches!(
+                error.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::NotADirectory
+            ) =>
+        {
+            return Ok(());
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    for entry in entries {
+        let name = entry?.file_name();
+        let name_str = name.to_string_lossy();
+
+        if ignore.iter().any(|ignore| ignore.matches(&name_str)) {
+            continue;
+        }
+
+        // A name that the key cannot resolve, such as a version outside of its range, is skipped
+        // rather than failing the whole search.
+        let values = match pattern.captures(&name_str) {
+            Some(captures) => match capture_values(&config.resolvers, &value, &captures) {
+                Ok(Some(values)) => values,
+                _ => continue,
+            },
+            None => continue,
+        };
+
+        // The captured values are passed down, so a field that is repeated in a later component
+        // must have the same value.
+        let mut path_fields = fields.clone();
+
+        if values.into_iter().any(|(key, value)| {
+            *path_fields.entry(key.to_owned()).or_insert(value.clone()) != value
+        }) {
+            continue;
+        }
+
+        let path = root.join(&name);
+
+        if rest.is_empty() {
+            found.push((path, path_fields));
+        } else if path.is_dir() {
+            recursive_find_paths(config, rest, &path_fields, ignore, path, found)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Find every path under the root for the catch-all `{**}`.
+///
+/// Symlinked directories are not followed, so a link back up the tree cannot loop forever.
+#[cfg(feature = "std")]
+fn recursive_find_all_paths(
+    ignore: &[glob::Pattern],
+    root: std::path::PathBuf,
+    out_paths: &mut Vec<std::path::PathBuf>,
+) -> Result<(), crate::Error> {
+    let dir = if root.as_os_str().is_empty() {
+        std::path::Path::new(".")
+    } else {
+        root.as_path()
+    };
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error)
+            if matches!(
+                error.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::NotADirectory
+            ) =>
+        {
+            return Ok(());
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+
+        if ignore
+            .iter()
+            .any(|ignore| ignore.matches(&name.to_string_lossy()))
+        {
+            continue;
+        }
+
+        let path = root.join(&name);
+
+        if entry.file_type()?.is_dir() {
+            out_paths.push(path.clone());
+            recursive_find_all_paths(ignore, path, out_paths)?;
+        } else {
+            out_paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// The async version of [recursive_find_all_paths].
+#[cfg(feature = "std")]
+fn recursive_find_all_paths_async<'a>(
+    ignore: &'a [glob::Pattern],
+    root: std::path::PathBuf,
+    out_paths: &'a mut Vec<std::path::PathBuf>,
+) -> std::pin::Pin<Box<dyn Future<Output = Result<(), crate::Error>> + Send + 'a>> {
+    Box::pin(async move {
+        let dir = if root.as_os_str().is_empty() {
+            std::path::Path::new(".")
+        } else {
+            root.as_path()
+        };
+
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    std::io::ErrorKind::NotFound | std::io::ErrorKind::NotADirectory
+                ) =>
+            {
+                return Ok(());
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name();
+
+            if ignore
+                .iter()
+                .any(|ignore| ignore.matches(&name.to_string_lossy()))
+            {
+                continue;
+            }
+
+            let path = root.join(&name);
+
+            if entry.file_type().await?.is_dir() {
+                out_paths.push(path.clone());
+                recursive_find_all_paths_async(ignore, path, out_paths).await?;
+            } else {
+                out_paths.push(path);
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// The async version of [recursive_find_paths].
+///
+/// The future is boxed, since an async function cannot call itself directly.
+#[cfg(feature = "std")]
+fn recursive_find_paths_async<'a>(
+    config: &'a crate::Config,
+    item: &'a [&'a crate::types::PathItem],
+    fields: &'a crate::types::PathAttributes,
+    ignore: &'a [glob::Pattern],
+    root: std::path::PathBuf,
+    out_paths: &'a mut Vec<std::path::PathBuf>,
+) -> std::pin::Pin<Box<dyn Future<Output = Result<(), crate::Error>> + Send + 'a>> {
+    Box::pin(async move {
+        let (part, rest) = match item.split_first() {
+            Some(value) => value,
+            None => {
+                if tokio::fs::try_exists(&root).await.unwrap_or(false) {
+                    out_paths.push(root);
+                }
+
+                return Ok(());
+            }
+        };
+
+        if part.path.is_catch_all() {
+            return recursive_find_all_paths_async(ignore, root, out_paths).await;
+        }
+
+        let value = part.path.try_to_literal_token(fields, &config.resolvers)?;
+
+        if !value.has_variable_tokens() {
+            let mut literal = String::new();
+            value.draw(&mut literal, fields, &config.resolvers)?;
+
+            return recursive_find_paths_async(
+                config,
+                rest,
+                fields,
+                ignore,
+                root.join(literal),
+                out_paths,
+            )
+            .await;
+        }
+
+        let mut pattern = String::new();
+        pattern.push('^');
+        value.draw_regex_pattern(&mut pattern, &config.resolvers)?;
+        pattern.push('$');
+        let pattern = crate::cache::regex(&pattern)?;
+
+        let dir = if root.as_os_str().is_empty() {
+            std::path::Path::new(".")
+        } else {
+            root.as_path()
+        };
+
+        let mut entries = match tokio::fs::read_dir(dir).await {
+            Ok(entries) => entries,
+            Err(error)
+                if matches!(
+                    error.kind(),
+                    std::io::ErrorKind::NotFound | std::io::ErrorKind::NotADirectory
+                ) =>
+            {
+                return Ok(());
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name();
+            let name_str = name.to_string_lossy();
+
+            if ignore.iter().any(|ignore| ignore.matches(&name_str)) {
+                continue;
+            }
+
+            // A name that the key cannot resolve, such as a version outside of its range, is
+            // skipped rather than failing the whole search.
+            let values = match pattern.captures(&name_str) {
+                Some(captures) => match capture_values(&config.resolvers, &value, &captures) {
+                    Ok(Some(values)) => values,
+                    _ => continue,
+                },
+                None => continue,
+            };
+
+            // The captured values are passed down, so a field that is repeated in a later
+            // component must have the same value.
+            let mut path_fields = fields.clone();
+
+            if values.into_iter().any(|(key, value)| {
+                *path_fields.entry(key.to_owned()).or_insert(value.clone()) != value
+            }) {
+                continue;
+            }
+
+            let path = root.join(&name);
+
+            if rest.is_empty() {
+                out_paths.push(path);
+            } else if tokio::fs::metadata(&path)
+                .await
+                .is_ok_and(|metadata| metadata.is_dir())
+            {
+                recursive_find_paths_async(config, rest, &path_fields, ignore, path, out_paths)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Owner, PathItemArgs, PathType, Permission};
+
+    use super::*;
+
+    fn path_item_args(key: &str, path: &str, parent: Option<&str>) -> PathItemArgs {
+        PathItemArgs {
+            key: key.try_into().unwrap(),
+            path: path.into(),
+            parent: parent.map(|parent| parent.try_into().unwrap()),
+            permission: Permission::default(),
+            owner: Owner::default(),
+            path_type: PathType::default(),
+            deferred: false,
+            order: 0,
+            description: None,
+            metadata: std::collections::HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_get_path_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/path/to/value"));
+    }
+
+    #[test]
+    fn test_get_path_with_path_fields_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}/v{version}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::PathFields::new();
+        fields.insert("thing", "value").unwrap();
+        fields.insert("version", 7u16).unwrap();
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/path/to/value/v007"));
+        assert_eq!(
+            get_fields(&config, "key", &path).unwrap(),
+            Some(fields.into())
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("/path/to/{name}", PathType::File, "/path/to/value.exr")]
+    #[case("/path/to/{name}", PathType::FileTemplate, "/path/to/value.exr")]
+    #[case("/path/to/{name}", PathType::Directory, "/path/to/value")]
+    #[case("/path/to/{name}.jpg", PathType::File, "/path/to/value.jpg")]
+    #[case("/path/to/{name}.{ext}", PathType::File, "/path/to/value.png")]
+    #[case("/path/to/{name}{ext=exr}", PathType::File, "/path/to/valuepng")]
+    #[case("/path/to/{name}_{ext|exr}", PathType::File, "/path/to/value_png")]
+    fn test_get_path_metadata_extension(
+        #[case] template: &str,
+        #[case] path_type: PathType,
+        #[case] expected: &str,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: template.into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: [(
+                    "ext".to_string(),
+                    crate::MetadataValue::String("exr".into()),
+                )]
+                .into_iter()
+                .collect(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("name".try_into().unwrap(), "value".into());
+            fields.insert("ext".try_into().unwrap(), "png".into());
+
+            fields
+        };
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from(expected));
+    }
+
+    #[test]
+    fn test_get_path_upto_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "sequence".try_into().unwrap(),
+                path: "/path/to/{sequence}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "shot".try_into().unwrap(),
+                path: "{shot}".into(),
+                parent: Some("sequence".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("sequence".try_into().unwrap(), "seq".into());
+
+            fields
+        };
+
+        let path = get_path_upto(&config, "shot", "sequence", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/path/to/seq"));
+
+        let err = get_path_upto(&config, "sequence", "shot", &fields).unwrap_err();
+
+        assert_eq!(err.to_string(), "'shot' is not an ancestor of 'sequence'");
+    }
+
+    #[test]
+    fn test_get_path_upto_field_defaults_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "sequence".try_into().unwrap(),
+                path: "/path/to/{show}/{sequence}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "shot".try_into().unwrap(),
+                path: "{shot}".into(),
+                parent: Some("sequence".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .set_field_default("show", "default_show".into())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("sequence".try_into().unwrap(), "seq".into());
+
+        assert_eq!(
+            get_path_upto(&config, "shot", "sequence", &fields).unwrap(),
+            std::path::PathBuf::from("/path/to/default_show/seq")
+        );
+
+        fields.insert("show".try_into().unwrap(), "show".into());
+
+        assert_eq!(
+            get_path_upto(&config, "shot", "sequence", &fields).unwrap(),
+            std::path::PathBuf::from("/path/to/show/seq")
+        );
+    }
+
+    #[test]
+    fn test_get_path_from_template_fields_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}/{version}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::TemplateAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+            fields.insert("version".try_into().unwrap(), 12i64.into());
+            fields.insert("unused".try_into().unwrap(), 1.5f64.into());
+
+            fields
+        };
+
+        let path = get_path_from_template_fields(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/path/to/value/012"));
+    }
+
+    #[test]
+    fn test_get_path_from_template_fields_float_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/scale_{scale}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_float_resolver("scale", 2)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::TemplateAttributes::new();
+            fields.insert("scale".try_into().unwrap(), 1.5f64.into());
+
+            fields
+        };
+
+        let path = get_path_from_template_fields(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/path/to/scale_1.50"));
+    }
+
+    #[rstest::rstest]
+    #[case(
+        crate::TemplateValue::Bool(true),
+        "Template value Bool(true) for \"thing\" cannot be used as a path value."
+    )]
+    #[case(
+        crate::TemplateValue::None,
+        "Template value None for \"thing\" cannot be used as a path value."
+    )]
+    #[case(
+        crate::TemplateValue::Array(Vec::new()),
+        "Template value Array([]) for \"thing\" cannot be used as a path value."
+    )]
+    #[case(crate::TemplateValue::Integer(-1), "Template value -1 for \"thing\" is out of range for a path value.")]
+    fn test_get_path_from_template_fields_failure(
+        #[case] value: crate::TemplateValue,
+        #[case] expected: &str,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::TemplateAttributes::new();
+            fields.insert("thing".try_into().unwrap(), value);
+
+            fields
+        };
+
+        let err = get_path_from_template_fields(&config, "key", &fields).unwrap_err();
+
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_resolve_in_roots_success() {
+        let fast_dir = tempfile::tempdir().unwrap();
+        let archive_dir = tempfile::tempdir().unwrap();
+        let expected_path = archive_dir.path().join("path/to/value");
+        std::fs::create_dir_all(&expected_path).unwrap();
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{root}/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+        let roots = [
+            fast_dir.path().to_path_buf(),
+            archive_dir.path().to_path_buf(),
+        ];
+
+        let path = resolve_in_roots(&config, "key", &fields, &roots).unwrap();
+        assert_eq!(path, Some(expected_path));
+
+        let path = resolve_in_roots(&config, "key", &fields, &roots[..1]).unwrap();
+        assert_eq!(path, None);
+    }
+
+    #[test]
+    fn test_get_fields_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let expected_fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let fields = get_fields(&config, "key", "/path/to/value")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(fields, expected_fields);
+    }
+
+    #[test]
+    fn test_get_fields_percent_encoded_round_trip_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_percent_encoded_string_resolver("name", None)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{name}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("name".try_into().unwrap(), "my shot".into());
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/path/to/my%20shot"));
+        assert_eq!(get_fields(&config, "key", &path).unwrap(), Some(fields));
+
+        let err = get_fields(&config, "key", "/path/to/my%2shot").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid value \"my%2shot\" for \"name\": Invalid percent-encoding in \"my%2shot\""
+        );
+
+        let err = get_fields(&config, "key", "/path/to/my%+1shot").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid value \"my%+1shot\" for \"name\": Invalid percent-encoding in \"my%+1shot\""
+        );
+    }
+
+    #[test]
+    fn test_get_fields_numeric_string_round_trip_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_numeric_string_resolver("id")
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{id}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let id = "1234567890123456789012345678901234567890";
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("id".try_into().unwrap(), id.into());
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from(format!("/path/to/{id}")));
+        assert_eq!(get_fields(&config, "key", &path).unwrap(), Some(fields));
+        assert_eq!(get_fields(&config, "key", "/path/to/12a4").unwrap(), None);
+    }
+
+    #[rstest::rstest]
+    #[case(
+        "/proj/shots/sh010/publish/v003/comp.exr",
+        Ok(Some("/mnt/projects/sh010/comp/comp_v0003.exr"))
+    )]
+    #[case("/proj/assets/chair/publish/v003/comp.exr", Ok(None))]
+    #[case(
+        "/proj/shots/sh010/publish/v003/comp",
+        Err("Could not find \"ext\" in the fields.")
+    )]
+    fn test_translate(#[case] path: &str, #[case] expected: Result<Option<&str>, &str>) {
+        let old_config = crate::ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                path_type: PathType::File,
+                ..path_item_args(
+                    "comp",
+                    "/proj/shots/{shot}/publish/v{version}/{name}{?.ext}",
+                    None,
+                )
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+        let new_config = crate::ConfigBuilder::new()
+            .add_integer_resolver("version", 4)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                path_type: PathType::File,
+                ..path_item_args(
+                    "comp",
+                    "/mnt/projects/{shot}/comp/{name}_v{version}.{ext}",
+                    None,
+                )
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let result = translate(&old_config, "comp", path, &new_config, "comp");
+
+        match expected {
+            Ok(expected) => assert_eq!(result.unwrap(), expected.map(std::path::PathBuf::from)),
+            Err(expected) => assert_eq!(result.unwrap_err().to_string(), expected),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(1, Ok("v001"))]
+    #[case(2, Ok("v002"))]
+    #[case(99, Ok("v099"))]
+    #[case(
+        0,
+        Err("Value 0 for \"version\" is out of range. Expected an integer from 1 to 99.")
+    )]
+    #[case(
+        100,
+        Err("Value 100 for \"version\" is out of range. Expected an integer from 1 to 99.")
+    )]
+    fn test_get_path_integer_bounds(#[case] value: u16, #[case] expected: Result<&str, &str>) {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver_with_bounds("version", 3, 1, 99)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/v{version}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("version".try_into().unwrap(), value.into());
+
+        match expected {
+            Ok(expected) => {
+                let path = get_path(&config, "key", &fields).unwrap();
+
+                assert_eq!(
+                    path,
+                    std::path::PathBuf::from(format!("/path/to/{expected}"))
+                );
+                assert_eq!(get_fields(&config, "key", &path).unwrap(), Some(fields));
+            }
+            Err(expected) => {
+                assert_eq!(
+                    get_path(&config, "key", &fields).unwrap_err().to_string(),
+                    expected
+                );
+                assert!(!config.is_valid_value(&"version".try_into().unwrap(), &value.into()));
+            }
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("/path/to/v000", None)]
+    #[case("/path/to/v001", Some(1))]
+    #[case("/path/to/v099", Some(99))]
+    #[case("/path/to/v100", None)]
+    fn test_get_fields_integer_bounds(#[case] path: &str, #[case] expected: Option<u16>) {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver_with_bounds("version", 3, 1, 99)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/v{version}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let result = get_fields(&config, "key", path).unwrap();
+
+        assert_eq!(
+            result.map(|mut fields| fields.remove(&"version".try_into().unwrap()).unwrap()),
+            expected.map(crate::PathValue::Integer)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_find_paths_integer_bounds_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        for version in ["v000", "v001", "v002", "v100"] {
+            std::fs::create_dir_all(root_dir.join("shot").join(version)).unwrap();
+        }
+
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver_with_bounds("version", 3, 1, 99)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{shot}/v{version}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = crate::types::PathAttributes::new();
+        let expected = vec![root_dir.join("shot/v001"), root_dir.join("shot/v002")];
+
+        assert_eq!(find_paths(&config, "key", &fields).unwrap(), expected);
+        assert_eq!(find_paths_glob(&config, "key", &fields).unwrap(), expected);
+        assert_eq!(
+            find_paths_async(&config, "key", &fields).await.unwrap(),
+            expected
+        );
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "shot".into());
+
+        assert_eq!(
+            find_latest(&config, "key", &fields, 5).unwrap(),
+            vec![root_dir.join("shot/v002"), root_dir.join("shot/v001")]
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(0, 7, "7")]
+    #[case(0, 1234, "1234")]
+    #[case(1, 7, "7")]
+    #[case(3, 7, "007")]
+    fn test_get_path_integer_padding_success(
+        #[case] padding: u8,
+        #[case] value: u16,
+        #[case] expected: &str,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("version", padding)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{version}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("version".try_into().unwrap(), value.into());
+
+        assert_eq!(
+            get_path(&config, "key", &fields).unwrap(),
+            std::path::PathBuf::from(format!("/path/to/{expected}"))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(0, "7", Some(7))]
+    #[case(0, "007", Some(7))]
+    #[case(0, "0", Some(0))]
+    #[case(1, "7", Some(7))]
+    #[case(1, "007", None)]
+    #[case(1, "0", Some(0))]
+    #[case(0, "", None)]
+    #[case(1, "", None)]
+    fn test_get_fields_integer_padding_success(
+        #[case] padding: u8,
+        #[case] value: &str,
+        #[case] expected: Option<u16>,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("version", padding)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/v{version}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let result = get_fields(&config, "key", format!("/path/to/v{value}")).unwrap();
+
+        assert_eq!(
+            result.map(|mut fields| fields.remove(&"version".try_into().unwrap()).unwrap()),
+            expected.map(crate::PathValue::Integer)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(1.5, 2, "1.50")]
+    #[case(-0.25, 2, "-0.25")]
+    #[case(24.0, 0, "24")]
+    #[case(23.976, 3, "23.976")]
+    fn test_get_fields_float_round_trip_success(
+        #[case] value: f64,
+        #[case] precision: u8,
+        #[case] drawn: &str,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_float_resolver("fps", precision)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{fps}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("fps".try_into().unwrap(), value.into());
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from(format!("/path/to/{drawn}")));
+        assert_eq!(get_fields(&config, "key", &path).unwrap(), Some(fields));
+    }
+
+    #[rstest::rstest]
+    #[case(
+        1u16.into(),
+        "Resolver type Float { precision: 2 } is invalid for value Integer(1)."
+    )]
+    #[case(
+        "fast".into(),
+        "Resolver type Float { precision: 2 } is invalid for value String(\"fast\")."
+    )]
+    #[case(f64::NAN.into(), "The float NaN for \"fps\" cannot be drawn in a path.")]
+    fn test_get_path_float_failure(#[case] value: crate::PathValue, #[case] expected: &str) {
+        let config = crate::ConfigBuilder::new()
+            .add_float_resolver("fps", 2)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{fps}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("fps".try_into().unwrap(), value);
+
+        let err = get_path(&config, "key", &fields).unwrap_err();
+
+        assert_eq!(err.to_string(), expected);
+        assert_eq!(get_fields(&config, "key", "/path/to/1.5").unwrap(), None);
+    }
+
+    #[rstest::rstest]
+    #[case("/path/to/model/a.b", Some(("model", "a.b")))]
+    #[case("/path/to/anim/a.b", Some(("anim", "a.b")))]
+    #[case("/path/to/r.g/a.b", Some(("r.g", "a.b")))]
+    #[case("/path/to/rig/a.b", None)]
+    #[case("/path/to/modeling/a.b", None)]
+    #[case("/path/to/temp/a.b", None)]
+    fn test_get_fields_choice_success(#[case] path: &str, #[case] expected: Option<(&str, &str)>) {
+        let config = crate::ConfigBuilder::new()
+            .add_choice_resolver("dept", ["model", "r.g", "anim"])
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{dept}/{name}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let expected = expected.map(|(dept, name)| {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("dept".try_into().unwrap(), dept.into());
+            fields.insert("name".try_into().unwrap(), name.into());
+            fields
+        });
+
+        if let Some(fields) = &expected {
+            assert_eq!(
+                get_path(&config, "key", fields).unwrap(),
+                std::path::PathBuf::from(path)
+            );
+        }
+
+        assert_eq!(get_fields(&config, "key", path).unwrap(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(
+        "rig".into(),
+        "Value \"rig\" for \"dept\" is not one of the options [\"model\", \"anim\"]."
+    )]
+    #[case(
+        1u16.into(),
+        "Resolver type Choice { options: [\"model\", \"anim\"] } is invalid for value Integer(1)."
+    )]
+    fn test_get_path_choice_failure(#[case] value: crate::PathValue, #[case] expected: &str) {
+        let config = crate::ConfigBuilder::new()
+            .add_choice_resolver("dept", ["model", "anim"])
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{dept}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("dept".try_into().unwrap(), value);
+
+        let err = get_path(&config, "key", &fields).unwrap_err();
+
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[cfg(feature = "date")]
+    #[rstest::rstest]
+    #[case("%Y_%m_%d", chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap().into(), "2024_01_31")]
+    #[case("%Y_%m_%d", "2024_1_31".into(), "2024_01_31")]
+    #[case("%y%j", chrono::NaiveDate::from_ymd_opt(2024, 2, 1).unwrap().into(), "24032")]
+    #[case("%d%%%m%%%Y", chrono::NaiveDate::from_ymd_opt(999, 1, 2).unwrap().into(), "02%01%0999")]
+    fn test_get_fields_date_round_trip_success(
+        #[case] format: &str,
+        #[case] value: crate::PathValue,
+        #[case] drawn: &str,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_date_resolver("date", format)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{date}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("date".try_into().unwrap(), value);
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from(format!("/path/to/{drawn}")));
+
+        let result = get_fields(&config, "key", &path).unwrap().unwrap();
+
+        assert_eq!(
+            result[&"date".try_into().unwrap()]
+                .to_drawn_string(config.resolvers.get(&"date".try_into().unwrap()).unwrap()),
+            drawn
+        );
+        assert!(matches!(
+            result[&"date".try_into().unwrap()],
+            crate::PathValue::Date(_)
+        ));
+    }
+
+    #[cfg(feature = "date")]
+    #[rstest::rstest]
+    #[case(
+        "2024-01-31".into(),
+        "The value \"2024-01-31\" is not a date in the format \"%Y_%m_%d\"."
+    )]
+    #[case(
+        "2024_02_30".into(),
+        "The value \"2024_02_30\" is not a date in the format \"%Y_%m_%d\"."
+    )]
+    #[case(
+        1u16.into(),
+        "Resolver type Date { format: \"%Y_%m_%d\" } is invalid for value Integer(1)."
+    )]
+    fn test_get_path_date_failure(#[case] value: crate::PathValue, #[case] expected: &str) {
+        let config = crate::ConfigBuilder::new()
+            .add_date_resolver("date", "%Y_%m_%d")
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{date}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("date".try_into().unwrap(), value);
+
+        let err = get_path(&config, "key", &fields).unwrap_err();
+
+        assert_eq!(err.to_string(), expected);
+        assert_eq!(
+            get_fields(&config, "key", "/path/to/2024_1_31").unwrap(),
+            None
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("/path/to/foo.txt", Some("foo"))]
+    #[case("/path/to/bar.txt", Some("bar"))]
+    #[case("/path/to/foobar.txt", None)]
+    #[case("/path/to/foo.txt.txt", None)]
+    #[case("/path/to/baz.txt", None)]
+    fn test_get_fields_string_resolver_alternation(
+        #[case] path: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_string_resolver("name", Some("foo|bar"))
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{name}.txt".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let expected = expected.map(|expected| {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("name".try_into().unwrap(), expected.into());
+
+            fields
+        });
+
+        assert_eq!(get_fields(&config, "key", path).unwrap(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("asset{v2}")]
+    #[case("{")]
+    #[case("}{")]
+    #[case("{shot}")]
+    fn test_get_fields_braces_round_trip(#[case] name: &str) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{name}_{shot}.exr".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("name".try_into().unwrap(), name.into());
+        fields.insert("shot".try_into().unwrap(), "sh010".into());
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(
+            path,
+            std::path::PathBuf::from(format!("/path/to/{name}_sh010.exr"))
+        );
+        assert_eq!(get_fields(&config, "key", &path).unwrap(), Some(fields));
+    }
+
+    #[rstest::rstest]
+    #[case("/path/to/007", Some(7))]
+    #[case("/path/to/070", Some(70))]
+    #[case("/path/to/700", Some(700))]
+    #[case("/path/to/1000", Some(1000))]
+    #[case("/path/to/65535", Some(65535))]
+    #[case("/path/to/07", None)]
+    #[case("/path/to/0007", None)]
+    #[case("/path/to/00001", None)]
+    fn test_get_fields_integer_padding_round_trip(
+        #[case] path: &str,
+        #[case] expected: Option<u16>,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("frame", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{frame}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = get_fields(&config, "key", path).unwrap();
+
+        assert_eq!(
+            fields
+                .as_ref()
+                .map(|fields| fields[&"frame".try_into().unwrap()].clone()),
+            expected.map(crate::PathValue::Integer)
+        );
+
+        if let Some(fields) = fields {
+            assert_eq!(
+                get_path(&config, "key", &fields).unwrap(),
+                std::path::PathBuf::from(path)
+            );
+        }
+    }
+
+    #[rstest::rstest]
+    #[case(None, "/path/to/shot.exr")]
+    #[case(Some("tif"), "/path/to/shot.tif")]
+    fn test_get_path_field_default(#[case] ext: Option<&str>, #[case] expected: &str) {
+        let config = crate::ConfigBuilder::new()
+            .set_field_default("ext", "exr".into())
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{name}.{ext}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("name".try_into().unwrap(), "shot".into());
+
+        if let Some(ext) = ext {
+            fields.insert("ext".try_into().unwrap(), ext.into());
+        }
+
+        assert_eq!(
+            get_path(&config, "key", &fields).unwrap(),
+            std::path::PathBuf::from(expected)
+        );
+        assert_eq!(
+            crate::get_workspace(&config, &fields)
+                .unwrap()
+                .last()
+                .map(|item| item.value().to_path_buf()),
+            Some(std::path::PathBuf::from(expected))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("/path/to/shot_v001.exr", "shot_v001", Some("exr"))]
+    #[case("/path/to/shot_v001", "shot_v001", None)]
+    #[case("/path/to/plate.1001.exr", "plate.1001", Some("exr"))]
+    fn test_get_fields_optional_extension(
+        #[case] path: &str,
+        #[case] name: &str,
+        #[case] ext: Option<&str>,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{name}{?.ext}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut expected = crate::types::PathAttributes::new();
+        expected.insert("name".try_into().unwrap(), name.into());
+
+        if let Some(ext) = ext {
+            expected.insert("ext".try_into().unwrap(), ext.into());
+        }
+
+        assert_eq!(
+            get_fields(&config, "key", path).unwrap(),
+            Some(expected.clone())
+        );
+        assert_eq!(
+            get_path(&config, "key", &expected).unwrap(),
+            std::path::PathBuf::from(path)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(
+        crate::TargetOs::Windows,
+        "a:b",
+        Some("The name \"a:b\" is not valid on Windows: it contains ':'.")
+    )]
+    #[case(
+        crate::TargetOs::Windows,
+        "CON",
+        Some("The name \"CON\" is not valid on Windows: it is a reserved name.")
+    )]
+    #[case(crate::TargetOs::Windows, "C:\\proj", None)]
+    #[case(crate::TargetOs::Windows, "proj/sh010", None)]
+    #[case(crate::TargetOs::Posix, "a:b", None)]
+    fn test_get_path_target_os(
+        #[case] target_os: crate::TargetOs,
+        #[case] root: &str,
+        #[case] expected_err: Option<&str>,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .set_target_os(target_os)
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{root}/a:literal/{name}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "child".try_into().unwrap(),
+                path: "child".into(),
+                parent: Some("key".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("root".try_into().unwrap(), root.into());
+        fields.insert("name".try_into().unwrap(), "shot".into());
+
+        let result = get_path(&config, "key", &fields);
+
+        assert_eq!(
+            result.as_ref().err().map(|err| err.to_string()),
+            expected_err.map(String::from)
+        );
+
+        let result = get_path_upto(&config, "child", "key", &fields);
+
+        assert_eq!(
+            result.as_ref().err().map(|err| err.to_string()),
+            expected_err.map(String::from)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(
+        "proj/sh010/comp/fx",
+        crate::GetFieldsResult::Mismatch {
+            index: 2,
+            pattern: "^work$".into(),
+            segment: "comp".into(),
+        }
+    )]
+    #[case(
+        "proj/shot10/work/fx",
+        crate::GetFieldsResult::Mismatch {
+            index: 1,
+            pattern: r"^sh((?:\d{3}|[1-9]\d{3,}?))$".into(),
+            segment: "shot10".into(),
+        }
+    )]
+    #[case(
+        "proj/sh010/work/fx",
+        crate::GetFieldsResult::Match(std::collections::HashMap::from([
+            ("shot".try_into().unwrap(), crate::PathValue::Integer(10)),
+            ("task".try_into().unwrap(), "fx".into()),
+        ]))
+    )]
+    fn test_get_fields_explain(#[case] path: &str, #[case] expected: crate::GetFieldsResult) {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("shot", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "proj/sh{shot}/work/{task}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(get_fields_explain(&config, "key", path).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_get_fields_failure_integer_out_of_range() {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("frame", 4)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{frame}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let err = get_fields(&config, "key", "/path/to/99999").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Invalid value \"99999\" for \"frame\": Error while parsing integer."
+        );
+        assert_eq!(
+            std::error::Error::source(&err).map(|source| source.to_string()),
+            Some("Error while parsing integer.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_fields_segments_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let segments = get_fields_segments(&config, "key", "/path/to/value")
+            .unwrap()
+            .unwrap();
+        let separator = std::path::MAIN_SEPARATOR;
+
+        assert_eq!(
+            segments,
+            vec![
+                crate::PathSegment::Literal(format!("{separator}path{separator}to{separator}")),
+                crate::PathSegment::Field("thing".try_into().unwrap(), "value".into()),
+            ]
+        );
+
+        let segments = get_fields_segments(&config, "key", "/other/to/value").unwrap();
+
+        assert_eq!(segments, None);
+    }
+
+    #[rstest::rstest]
+    #[case("/path//to/{thing}", "/path/to/value")]
+    #[case("/path/to//{thing}", "/path/to/value")]
+    #[case("/path\\\\to/{thing}", "/path/to/value")]
+    #[case("/path/to/{thing}", "/path//to/value")]
+    fn test_get_fields_repeated_separators(#[case] template: &str, #[case] path: &str) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: template.into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let expected_fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let fields = get_fields(&config, "key", path).unwrap().unwrap();
+
+        assert_eq!(fields, expected_fields);
+        assert_eq!(
+            get_path(&config, "key", &expected_fields).unwrap(),
+            std::path::PathBuf::from("/path/to/value")
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("/path/a/a/a", Ok(Some("a")))]
+    #[case(
+        "/path/a/b/c",
+        Err(
+            "Mismatched field values: \"id\" has the values [String(\"a\"), String(\"b\"), String(\"c\")]"
+        )
+    )]
+    #[case(
+        "/path/a/b/a",
+        Err("Mismatched field values: \"id\" has the values [String(\"a\"), String(\"b\")]")
+    )]
+    fn test_get_fields_repeated_field(
+        #[case] path: &str,
+        #[case] expected: Result<Option<&str>, &str>,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/{id}/{id}/{id}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let result = get_fields(&config, "key", path);
+
+        match expected {
+            Ok(expected) => {
+                let fields = result.unwrap();
+                let key = "id".try_into().unwrap();
+                let id = fields.as_ref().and_then(|fields| fields.get(&key));
+                assert_eq!(id, expected.map(crate::PathValue::from).as_ref());
+            }
+            Err(expected) => assert_eq!(result.unwrap_err().to_string(), expected),
+        }
+    }
+
+    #[rstest::rstest]
+    #[case("/path/to/value", '/')]
+    #[case("\\path\\to\\value", '\\')]
+    #[case("/path//to/value/", '/')]
+    fn test_get_fields_str_success(#[case] path: &str, #[case] separator: char) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let expected_fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let fields = get_fields_str(&config, "key", path, separator)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(fields, expected_fields);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_get_fields_str_windows_forward_slash_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "C:\\path\\to\\{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let expected_fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let fields = get_fields_str(&config, "key", "C:/path/to/value", '/')
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(fields, expected_fields);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_get_path_verbatim_prefix_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: r"\\?\C:\projects".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{thing}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let path = get_path(&config, "key", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from(r"\\?\C:\projects\value"));
+        assert_eq!(
+            get_fields(&config, "key", &path).unwrap(),
+            Some(fields.clone())
+        );
+
+        let resolved_items = crate::get_workspace(&config, &fields).unwrap();
+
+        for resolved_item in resolved_items {
+            assert!(
+                resolved_item
+                    .value()
+                    .to_string_lossy()
+                    .starts_with(r"\\?\C:\")
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_key_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let result = get_key(&config, "/path/to/value", &fields)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.to_string(), "key");
+    }
+
+    #[rstest::rstest]
+    #[case("/path/to/value", &["alias", "key", "template"])]
+    #[case("/path/to/other/value", &["other"])]
+    #[case("/path/from/value", &[])]
+    fn test_get_keys_success(#[case] path: &str, #[case] expected: &[&str]) {
+        let mut builder = crate::ConfigBuilder::new();
+
+        for (key, path) in [
+            ("template", "/path/to/{thing}"),
+            ("key", "/path/to/{thing}"),
+            ("other", "/path/to/other/{thing}"),
+            ("alias", "/path/to/{thing}"),
+        ] {
+            builder = builder
+                .add_path_item(path_item_args(key, path, None))
+                .unwrap();
+        }
+
+        let config = builder.build().unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("thing".try_into().unwrap(), "value".into());
+
+        let result = get_keys(&config, path, &fields).unwrap();
+
+        assert_eq!(
+            result.iter().map(|key| key.as_str()).collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_find_paths_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+        let mut expected_paths = Vec::new();
+
+        {
+            let test_dir = root_dir.join("path/to");
+            std::fs::create_dir_all(&test_dir).unwrap();
+
+            for index in 0..5 {
+                let task_test_dir = test_dir.clone();
+
+                let path = task_test_dir.join(format!("value_{}.txt", index));
+                std::fs::write(&path, "test").unwrap();
+                expected_paths.push(path);
+            }
+
+            expected_paths.sort();
+        }
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "path/to/{thing}_{frame}.txt".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let mut result_paths = find_paths(&config, "key", &fields).unwrap();
+        result_paths.sort();
+
+        assert_eq!(expected_paths, result_paths);
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_find_paths_async_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+        let mut expected_paths = Vec::new();
+
+        {
+            let test_dir = root_dir.join("path/to");
+            std::fs::create_dir_all(&test_dir).unwrap();
+
+            for index in 0..5 {
+                let task_test_dir = test_dir.clone();
+
+                let path = task_test_dir.join(format!("value_{}.txt", index));
+                std::fs::write(&path, "test").unwrap();
+                expected_paths.push(path);
+            }
+
+            expected_paths.sort();
+        }
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "path/to/{thing}_{frame}.txt".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+
+        let mut result_paths = find_paths_async(&config, "key", &fields).await.unwrap();
+        result_paths.sort();
+
+        assert_eq!(expected_paths, result_paths);
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_find_paths_catch_all_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        for path in ["proj/cache/x/y", "proj/other"] {
+            std::fs::create_dir_all(root_dir.join(path)).unwrap();
+        }
+
+        for path in [
+            "proj/cache/a.txt",
+            "proj/cache/x/y/b.txt",
+            "proj/other/c.txt",
+        ] {
+            std::fs::write(root_dir.join(path), "test").unwrap();
+        }
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "cache".try_into().unwrap(),
+                path: root_dir.join("{project}/cache/{**}"),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("project".try_into().unwrap(), "proj".into());
+
+        let expected_paths = ["a.txt", "x", "x/y", "x/y/b.txt"]
+            .into_iter()
+            .map(|path| root_dir.join("proj/cache").join(path))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            find_paths(&config, "cache", &fields).unwrap(),
+            expected_paths
+        );
+        assert_eq!(
+            find_paths_async(&config, "cache", &fields).await.unwrap(),
+            expected_paths
+        );
+        assert_eq!(
+            find_paths_glob(&config, "cache", &fields).unwrap(),
+            expected_paths
+        );
+        assert_eq!(
+            get_fields(&config, "cache", &expected_paths[3]).unwrap(),
+            Some(fields.clone())
+        );
+
+        let err = get_path(&config, "cache", &fields).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "The catch-all {**} can only be used to find paths."
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_find_paths_glob_matches_find_paths() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        // A version that overflows the integer is skipped by both searches.
+        for shot in ["sh010", "sh020", "notes"] {
+            for version in ["v001", "v002", "vXYZ", "v99999"] {
+                let dir = root_dir.join("[shots]").join(shot).join(version);
+                std::fs::create_dir_all(&dir).unwrap();
+                std::fs::write(dir.join("image.exr"), "test").unwrap();
+            }
+        }
+
+        std::fs::write(root_dir.join("[shots]/sh010/v003"), "not a directory").unwrap();
+
+        let config = crate::ConfigBuilder::new()
+            .add_string_resolver("shot", Some(r"sh\d{3}"))
+            .unwrap()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "[shots]/{shot}/v{version}/image.exr".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut shot_fields = crate::types::PathAttributes::new();
+        shot_fields.insert("shot".try_into().unwrap(), "sh020".into());
+        let mut version_fields = crate::types::PathAttributes::new();
+        version_fields.insert("version".try_into().unwrap(), 2u16.into());
+
+        for fields in [
+            crate::types::PathAttributes::new(),
+            shot_fields,
+            version_fields,
+        ] {
+            let expected_paths = find_paths(&config, "key", &fields).unwrap();
+            let result_paths = find_paths_glob(&config, "key", &fields).unwrap();
+
+            assert!(!expected_paths.is_empty());
+            assert_eq!(expected_paths, result_paths);
+        }
+
+        assert_eq!(
+            find_paths_glob(&config, "key", &crate::types::PathAttributes::new())
+                .unwrap()
+                .len(),
+            4
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_find_paths_multiple_variables_in_component_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        for name in ["plate.exr", "notes.txt", "README"] {
+            std::fs::write(root_dir.join(name), "test").unwrap();
+        }
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "file".try_into().unwrap(),
+                path: "{name}.{ext}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let paths = find_paths(&config, "file", &crate::types::PathAttributes::new()).unwrap();
+
+        assert_eq!(
+            paths,
+            vec![root_dir.join("notes.txt"), root_dir.join("plate.exr")]
+        );
+
+        let name_key = "name".try_into().unwrap();
+        let ext_key = "ext".try_into().unwrap();
+        let captures = paths
+            .iter()
+            .map(|path| {
+                let fields = get_fields(&config, "file", path).unwrap().unwrap();
+                (fields[&name_key].clone(), fields[&ext_key].clone())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            captures,
+            vec![
+                ("notes".into(), "txt".into()),
+                ("plate".into(), "exr".into()),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_find_latest_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        for version in [1, 2, 9, 10, 3] {
+            std::fs::create_dir_all(root_dir.join(format!("shot/v{version:03}"))).unwrap();
+        }
+
+        std::fs::create_dir_all(root_dir.join("other/v011")).unwrap();
+
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{shot}/v{version}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "shot".into());
 
-    Ok(out_paths)
-}
+        let paths = find_latest(&config, "key", &fields, 2).unwrap();
 
-#[cfg(test)]
-mod tests {
-    use crate::{Owner, PathItemArgs, PathType, Permission};
+        assert_eq!(
+            paths,
+            vec![root_dir.join("shot/v010"), root_dir.join("shot/v009")]
+        );
 
-    use super::*;
+        let err = find_latest(&config, "key", &crate::types::PathAttributes::new(), 2).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Expected exactly one unresolved field, found [\"shot\", \"version\"]"
+        );
+    }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn test_get_path_success() {
+    fn test_find_paths_with_fields_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        std::fs::create_dir_all(root_dir.join("shot")).unwrap();
+        std::fs::create_dir_all(root_dir.join("other")).unwrap();
+
+        for frame in [1001, 1002] {
+            std::fs::write(root_dir.join(format!("shot/render.{frame}.exr")), "test").unwrap();
+        }
+
+        std::fs::write(root_dir.join("other/render.1001.exr"), "test").unwrap();
+
         let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("frame", 4)
+            .unwrap()
             .add_path_item(PathItemArgs {
-                key: "key".try_into().unwrap(),
-                path: "/path/to/{thing}".into(),
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
                 parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{shot}/render.{frame}.exr".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
             .build()
             .unwrap();
 
-        let fields = {
-            let mut fields = crate::types::PathAttributes::new();
-            fields.insert("thing".try_into().unwrap(), "value".into());
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "shot".into());
 
-            fields
-        };
+        let found = find_paths_with_fields(&config, "key", &fields).unwrap();
 
-        let path = get_path(&config, "key", &fields).unwrap();
+        let expected = [1001, 1002]
+            .into_iter()
+            .map(|frame| {
+                let mut expected_fields = fields.clone();
+                expected_fields.insert(
+                    "frame".try_into().unwrap(),
+                    crate::PathValue::Integer(frame),
+                );
 
-        assert_eq!(path, std::path::PathBuf::from("/path/to/value"));
+                (
+                    root_dir.join(format!("shot/render.{frame}.exr")),
+                    expected_fields,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, expected);
     }
 
-    #[test]
-    fn test_get_fields_success() {
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_find_paths_with_fields_repeated_field_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        for (shot, name) in [("a", "a_x"), ("a", "b_x"), ("b", "b_x")] {
+            std::fs::create_dir_all(root_dir.join(shot).join(name)).unwrap();
+        }
+
         let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
             .add_path_item(PathItemArgs {
                 key: "key".try_into().unwrap(),
-                path: "/path/to/{thing}".into(),
+                path: "{shot}/{shot}_x".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let found =
+            find_paths_with_fields(&config, "key", &crate::types::PathAttributes::new()).unwrap();
+
+        let expected = ["a", "b"]
+            .into_iter()
+            .map(|shot| {
+                let mut expected_fields = crate::types::PathAttributes::new();
+                expected_fields.insert("shot".try_into().unwrap(), shot.into());
+
+                (
+                    root_dir.join(shot).join(format!("{shot}_x")),
+                    expected_fields,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, expected);
+        let expected = expected
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            find_paths(&config, "key", &crate::types::PathAttributes::new()).unwrap(),
+            expected
+        );
+        assert_eq!(
+            find_paths_async(&config, "key", &crate::types::PathAttributes::new())
+                .await
+                .unwrap(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_find_paths_with_options_ignore_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        for dir in ["src", "__pycache__"] {
+            std::fs::create_dir_all(root_dir.join(dir)).unwrap();
+            std::fs::write(root_dir.join(dir).join("module.py"), "test").unwrap();
+        }
+
+        std::fs::write(root_dir.join("src").join("module.py.tmp"), "test").unwrap();
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
                 parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{dir}/{file}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
             .build()
             .unwrap();
 
-        let expected_fields = {
-            let mut fields = crate::types::PathAttributes::new();
-            fields.insert("thing".try_into().unwrap(), "value".into());
+        let fields = crate::types::PathAttributes::new();
 
-            fields
+        let result_paths = find_paths(&config, "key", &fields).unwrap();
+        assert_eq!(result_paths.len(), 3);
+
+        let options = crate::FindPathsOptions {
+            ignore: vec!["__pycache__".to_string(), "*.tmp".to_string()],
+            relative: false,
         };
+        let result_paths = find_paths_with_options(&config, "key", &fields, &options).unwrap();
 
-        let fields = get_fields(&config, "key", "/path/to/value")
+        assert_eq!(result_paths, vec![root_dir.join("src").join("module.py")]);
+        assert_eq!(
+            find_paths_with_options_async(&config, "key", &fields, &options)
+                .await
+                .unwrap(),
+            result_paths
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case("{root}")]
+    #[case("/does/not/exist/{root}")]
+    fn test_find_paths_from_success(#[case] root_path: &str) {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+        let mut expected_paths = Vec::new();
+
+        for shot in ["sh010", "sh020"] {
+            let plates_dir = root_dir.join(shot).join("plates");
+            std::fs::create_dir_all(&plates_dir).unwrap();
+            std::fs::write(plates_dir.join("plate.exr"), "test").unwrap();
+            expected_paths.push(plates_dir.join("plate.exr"));
+        }
+
+        std::fs::create_dir_all(root_dir.join("sh030").join("renders")).unwrap();
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_path.into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{shot}/plates/{name}.exr".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
             .unwrap()
+            .build()
             .unwrap();
 
-        assert_eq!(fields, expected_fields);
+        let fields = crate::types::PathAttributes::new();
+        let result_paths = find_paths_from(&config, "key", &fields, root_dir).unwrap();
+
+        assert_eq!(result_paths, expected_paths);
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "sh020".into());
+        let result_paths = find_paths_from(&config, "key", &fields, root_dir).unwrap();
+
+        assert_eq!(result_paths, expected_paths[1..]);
     }
 
-    #[test]
-    fn test_get_key_success() {
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case("/x/{thing}")]
+    #[case("x/{thing}")]
+    fn test_find_paths_from_without_parent_success(#[case] path: &str) {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+        std::fs::create_dir_all(root_dir.join("x").join("val")).unwrap();
+
         let config = crate::ConfigBuilder::new()
             .add_path_item(PathItemArgs {
                 key: "key".try_into().unwrap(),
-                path: "/path/to/{thing}".into(),
+                path: path.into(),
                 parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
             .build()
             .unwrap();
 
-        let fields = {
-            let mut fields = crate::types::PathAttributes::new();
-            fields.insert("thing".try_into().unwrap(), "value".into());
+        let result_paths = find_paths_from(
+            &config,
+            "key",
+            &crate::types::PathAttributes::new(),
+            root_dir,
+        )
+        .unwrap();
 
-            fields
-        };
+        assert_eq!(result_paths, vec![root_dir.join("x").join("val")]);
+    }
 
-        let result = get_key(&config, "/path/to/value", &fields)
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_find_paths_many_siblings_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+        let mut expected_paths = Vec::new();
+
+        for index in 0..300 {
+            let shot_dir = root_dir.join(format!("sh{index:03}"));
+            std::fs::create_dir_all(shot_dir.join("plates")).unwrap();
+            std::fs::write(shot_dir.join("plates").join("plate.exr"), "test").unwrap();
+            expected_paths.push(shot_dir.join("plates").join("plate.exr"));
+        }
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
             .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "{shot}/plates/{name}.exr".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
             .unwrap();
 
-        assert_eq!(result.to_string(), "key");
+        let fields = crate::types::PathAttributes::new();
+        let result_paths = find_paths(&config, "key", &fields).unwrap();
+
+        assert_eq!(result_paths, expected_paths);
     }
 
-    #[test]
-    fn test_find_paths_success() {
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case("missing/{thing}")]
+    #[case("missing/nested/{thing}")]
+    #[case("file.txt/{thing}")]
+    #[tokio::test]
+    async fn test_find_paths_missing_literal_directory_success(#[case] path: &str) {
         let tmp_dir = tempfile::tempdir().unwrap();
         let root_dir = tmp_dir.path();
-        let mut expected_paths = Vec::new();
+        std::fs::write(root_dir.join("file.txt"), "test").unwrap();
 
-        {
-            let test_dir = root_dir.join("path/to");
-            std::fs::create_dir_all(&test_dir).unwrap();
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: path.into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
 
-            for index in 0..5 {
-                let task_test_dir = test_dir.clone();
+        let fields = crate::types::PathAttributes::new();
 
-                let path = task_test_dir.join(format!("value_{}.txt", index));
-                std::fs::write(&path, "test").unwrap();
-                expected_paths.push(path);
-            }
+        assert!(find_paths(&config, "key", &fields).unwrap().is_empty());
+        assert!(find_paths_glob(&config, "key", &fields).unwrap().is_empty());
+        assert!(
+            find_paths_async(&config, "key", &fields)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
 
-            expected_paths.sort();
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case("proj", None)]
+    #[case("{root}", None)]
+    #[case("{root}", Some("proj"))]
+    #[tokio::test]
+    async fn test_find_paths_with_options_relative_success(
+        #[case] root_path: &str,
+        #[case] root: Option<&str>,
+    ) {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let test_dir = tmp_dir.path().join("proj/path/to");
+        std::fs::create_dir_all(&test_dir).unwrap();
+
+        for index in 0..2 {
+            std::fs::write(test_dir.join(format!("value_{index:03}.txt")), "test").unwrap();
         }
 
         let config = crate::ConfigBuilder::new()
             .add_path_item(PathItemArgs {
                 key: "root".try_into().unwrap(),
-                path: root_dir.to_path_buf(),
+                path: tmp_dir.path().join(root_path),
                 parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -444,22 +4640,39 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::File,
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
             .build()
             .unwrap();
 
-        let fields = {
-            let mut fields = crate::types::PathAttributes::new();
-            fields.insert("thing".try_into().unwrap(), "value".into());
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("thing".try_into().unwrap(), "value".into());
 
-            fields
-        };
+        if let Some(root) = root {
+            fields.insert("root".try_into().unwrap(), root.into());
+        }
 
-        let mut result_paths = find_paths(&config, "key", &fields).unwrap();
-        result_paths.sort();
+        let options = crate::FindPathsOptions {
+            ignore: Vec::new(),
+            relative: true,
+        };
+        let result_paths = find_paths_with_options(&config, "key", &fields, &options).unwrap();
 
-        assert_eq!(expected_paths, result_paths);
+        assert_eq!(
+            result_paths,
+            vec![
+                std::path::PathBuf::from("path/to/value_000.txt"),
+                std::path::PathBuf::from("path/to/value_001.txt"),
+            ]
+        );
+        assert_eq!(
+            find_paths_with_options_async(&config, "key", &fields, &options)
+                .await
+                .unwrap(),
+            result_paths
+        );
     }
 }
@@ -1,49 +1,872 @@
-use crate::types::{FieldKey, PathItem, PathItemArgs, Resolver, Resolvers, Tokens};
+use crate::types::{
+    FieldKey, PathItem, PathItemArgs, Resolver, ResolverSpec, Resolvers, Token, Tokens,
+};
 
 /// Store the resolver configs.
 ///
 /// The config stores two major components. The resolvers, which are responsible for resolving the
 /// placholder values, and the items, which are all of the path parts that are used to find paths
 /// or used to create paths.
-#[derive(Debug, Clone)]
+///
+/// A built config can be serialized and deserialized with serde, such as to cache it on disk
+/// instead of running the builder again. The items are stored as their path strings, and a
+/// deserialized config is validated the same way as [ConfigBuilder::build].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(into = "ConfigData", try_from = "ConfigData")]
 pub struct Config {
     pub(crate) resolvers: Resolvers,
     pub(crate) item_map: std::collections::HashMap<FieldKey, usize>,
+    pub(crate) index_key_map: std::collections::HashMap<usize, FieldKey>,
     pub(crate) items: Vec<PathItem>,
+    /// The item indexes from the root to the item for each key, so resolving a key doesn't need to
+    /// walk the parents on every call.
+    pub(crate) chain_map: std::collections::HashMap<FieldKey, Vec<usize>>,
+    pub(crate) composite_map: std::collections::HashMap<FieldKey, Vec<FieldKey>>,
+    pub(crate) profiles: std::collections::HashMap<String, crate::types::PathAttributes>,
+    pub(crate) secret_fields: std::collections::HashSet<FieldKey>,
+    pub(crate) field_defaults: crate::types::PathAttributes,
+    pub(crate) warnings: Vec<ConfigWarning>,
+    pub(crate) target_os: Option<crate::TargetOs>,
+}
+
+/// The serialized form of a [Config].
+///
+/// The lookup tables that are derived from the items, such as the chain map, are not stored, and
+/// are rebuilt when the config is deserialized.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ConfigData {
+    resolvers: Resolvers,
+    item_map: std::collections::HashMap<FieldKey, usize>,
+    items: Vec<PathItemData>,
+    #[serde(default)]
+    composite_map: std::collections::HashMap<FieldKey, Vec<FieldKey>>,
+    #[serde(default)]
+    profiles: std::collections::HashMap<String, crate::types::PathAttributes>,
+    #[serde(default)]
+    secret_fields: std::collections::HashSet<FieldKey>,
+    #[serde(default)]
+    field_defaults: crate::types::PathAttributes,
+    #[serde(default)]
+    target_os: Option<crate::TargetOs>,
+}
+
+/// The serialized form of a [PathItem], with the path stored as its string.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PathItemData {
+    path: String,
+    parent: Option<usize>,
+    permission: crate::Permission,
+    owner: crate::Owner,
+    path_type: crate::PathType,
+    deferred: bool,
+    order: i32,
+    description: Option<String>,
+    metadata: std::collections::HashMap<String, crate::MetadataValue>,
+}
+
+impl From<Config> for ConfigData {
+    fn from(config: Config) -> Self {
+        Self {
+            resolvers: config.resolvers,
+            item_map: config.item_map,
+            items: config
+                .items
+                .into_iter()
+                .map(|item| PathItemData {
+                    path: item.path.to_string(),
+                    parent: item.parent,
+                    permission: item.permission,
+                    owner: item.owner,
+                    path_type: item.path_type,
+                    deferred: item.deferred,
+                    order: item.order,
+                    description: item.description,
+                    metadata: item.metadata,
+                })
+                .collect(),
+            composite_map: config.composite_map,
+            profiles: config.profiles,
+            secret_fields: config.secret_fields,
+            field_defaults: config.field_defaults,
+            target_os: config.target_os,
+        }
+    }
+}
+
+impl TryFrom<ConfigData> for Config {
+    type Error = crate::Error;
+
+    fn try_from(data: ConfigData) -> Result<Self, Self::Error> {
+        let item_count = data.items.len();
+        let mut items = Vec::with_capacity(item_count);
+
+        for (index, item) in data.items.into_iter().enumerate() {
+            // A built config always has the parents before their children, so a parent that is
+            // not before its child is from a loop in the parents.
+            match item.parent {
+                Some(parent) if parent >= item_count => {
+                    return Err(crate::Error::new(format!("Missing parent: {parent}")));
+                }
+                Some(parent) if parent >= index => {
+                    return Err(crate::Error::new(format!(
+                        "Infinite recursion error with item {index} and parent {parent}"
+                    )));
+                }
+                _ => (),
+            }
+
+            items.push(PathItem {
+                path: Tokens::new(&item.path)?,
+                parent: item.parent,
+                permission: item.permission,
+                owner: item.owner,
+                path_type: item.path_type,
+                deferred: item.deferred,
+                order: item.order,
+                description: item.description,
+                metadata: item.metadata,
+                pattern: String::new(),
+            });
+        }
+
+        for (key, index) in data.item_map.iter() {
+            if *index >= item_count {
+                return Err(crate::Error::new(format!(
+                    "Missing item {index} for {:?}.",
+                    key.as_str()
+                )));
+            }
+        }
+
+        for (key, parts) in data.composite_map.iter() {
+            if data.item_map.contains_key(key) {
+                return Err(crate::Error::new(format!(
+                    "'{}' already in path items.",
+                    key
+                )));
+            }
+
+            for part in parts.iter() {
+                if !data.item_map.contains_key(part) {
+                    return Err(crate::Error::new(format!("Missing composite part: {part}")));
+                }
+            }
+        }
+
+        let config = Self {
+            resolvers: data.resolvers,
+            items,
+            item_map: data.item_map,
+            chain_map: std::collections::HashMap::new(),
+            index_key_map: std::collections::HashMap::new(),
+            composite_map: data.composite_map,
+            profiles: data.profiles,
+            secret_fields: data.secret_fields,
+            field_defaults: data.field_defaults,
+            warnings: Vec::new(),
+            target_os: data.target_os,
+        };
+
+        config.finish()
+    }
 }
 
 impl Config {
+    /// Validate the items, and fill in the lookup tables that are derived from them.
+    ///
+    /// This is shared by [ConfigBuilder::build] and deserializing a config, so both are validated
+    /// the same way.
+    fn finish(mut self) -> Result<Self, crate::Error> {
+        for item in self.items.iter_mut() {
+            item.path.validate_defaults(&self.resolvers)?;
+
+            // Draw the pattern once, so matching paths doesn't need to escape the literals again.
+            item.pattern.clear();
+            item.path
+                .draw_regex_pattern(&mut item.pattern, &self.resolvers)?;
+        }
+
+        for item in self.items.iter() {
+            if item.path.tokens.contains(&Token::CatchAll) && !item.path.is_catch_all() {
+                return Err(crate::Error::new(format!(
+                    "The catch-all {{**}} must be a whole path component, found {:?}.",
+                    item.path.to_string()
+                )));
+            }
+
+            if let Some(parent) = item.parent
+                && self.items[parent].path.is_catch_all()
+            {
+                return Err(crate::Error::new(
+                    "The catch-all {**} must be the last path component.",
+                ));
+            }
+        }
+
+        // Invert the item map once, so the workspace resolver doesn't need to do it per call.
+        let index_key_map = self
+            .item_map
+            .iter()
+            .map(|(key, index)| (*index, key.to_owned()))
+            .collect();
+
+        let chain_map = self
+            .item_map
+            .iter()
+            .map(|(key, index)| {
+                let mut chain = vec![*index];
+
+                while let Some(parent_index) = self.items[chain[chain.len() - 1]].parent {
+                    chain.push(parent_index);
+                }
+
+                chain.reverse();
+
+                (key.to_owned(), chain)
+            })
+            .collect::<std::collections::HashMap<_, _>>();
+
+        // Items with different placeholders but the same patterns, such as `/a/{x}` and `/a/{y}`,
+        // would map to the same paths for equal values.
+        let mut shape_key_map: std::collections::HashMap<Vec<&str>, (&FieldKey, &Vec<usize>)> =
+            std::collections::HashMap::new();
+        let mut keys = chain_map.keys().collect::<Vec<_>>();
+        keys.sort();
+
+        for key in keys {
+            let chain = &chain_map[key];
+            let shape = chain
+                .iter()
+                .map(|index| self.items[*index].pattern.as_str())
+                .collect::<Vec<_>>();
+
+            match shape_key_map.get(&shape) {
+                Some((other_key, other_chain)) if *other_chain != chain => {
+                    return Err(crate::Error::new(format!(
+                        "Path items {:?} and {:?} resolve to the same paths.",
+                        other_key.as_str(),
+                        key.as_str()
+                    )));
+                }
+                Some(_) => (),
+                None => {
+                    shape_key_map.insert(shape, (key, chain));
+                }
+            }
+        }
+
+        // A deferred item is only created when one of its descendants is created, so a deferred
+        // item without any non-deferred descendants is never created.
+        let mut can_create = self
+            .items
+            .iter()
+            .map(|item| !item.deferred && !item.path.is_catch_all())
+            .collect::<Vec<_>>();
+
+        // The parents are always before their children, so walking backwards visits every child
+        // before its parent.
+        for index in (0..self.items.len()).rev() {
+            if can_create[index]
+                && let Some(parent) = self.items[index].parent
+            {
+                can_create[parent] = true;
+            }
+        }
+
+        let mut warnings = self
+            .item_map
+            .iter()
+            .filter(|(_, index)| !can_create[**index])
+            .map(|(key, _)| ConfigWarning::DeadDeferredItem(key.to_owned()))
+            .collect::<Vec<_>>();
+        warnings.sort_by(|a, b| a.key().cmp(b.key()));
+
+        self.chain_map = chain_map;
+        self.index_key_map = index_key_map;
+        self.warnings = warnings;
+
+        for (key, value) in self.field_defaults.iter() {
+            if !self.is_valid_value(key, value) {
+                return Err(crate::Error::new(format!(
+                    "Default {value:?} is invalid for {:?}.",
+                    key.as_str()
+                )));
+            }
+        }
+
+        Ok(self)
+    }
+
     pub(crate) fn get_item(&self, key: &FieldKey) -> Option<Vec<&PathItem>> {
-        let last_id = match self.item_map.get(key) {
-            Some(id) => *id,
-            None => return None,
+        if let Some(parts) = self.composite_map.get(key) {
+            let mut items = Vec::new();
+
+            for part in parts.iter() {
+                items.extend(self.get_item(part)?);
+            }
+
+            return Some(items);
+        }
+
+        self.chain_map
+            .get(key)
+            .map(|chain| chain.iter().map(|index| &self.items[*index]).collect())
+    }
+
+    /// A hash of the config's definition, such as for caching built configs.
+    ///
+    /// Configs with the same resolvers, path items, composite items, profiles, and field settings
+    /// have the same hash, even if they were built independently. The hash is only stable for the
+    /// same version of this crate, so it should not be stored between versions.
+    pub fn content_hash(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        fn sorted<K: Ord, V>(map: &std::collections::HashMap<K, V>) -> Vec<(&K, &V)> {
+            let mut items = map.iter().collect::<Vec<_>>();
+            items.sort_by_key(|(key, _)| *key);
+            items
+        }
+
+        // The item indexes depend on the order that the items were added in, so the items are
+        // hashed by their full paths instead.
+        let item_paths = (0..self.items.len())
+            .map(|index| {
+                let mut parts = Vec::new();
+                let mut current = Some(index);
+
+                while let Some(index) = current {
+                    parts.push(self.items[index].path.to_string());
+                    current = self.items[index].parent;
+                }
+
+                parts.into_iter().rev().collect::<std::path::PathBuf>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+
+        sorted(&self.resolvers).hash(&mut hasher);
+        sorted(&self.composite_map).hash(&mut hasher);
+        sorted(&self.field_defaults).hash(&mut hasher);
+        self.target_os.hash(&mut hasher);
+
+        for (key, index) in sorted(&self.item_map) {
+            key.hash(&mut hasher);
+            item_paths[*index].hash(&mut hasher);
+        }
+
+        let mut indexes = (0..self.items.len()).collect::<Vec<_>>();
+        indexes.sort_by_key(|index| &item_paths[*index]);
+
+        for index in indexes {
+            let item = &self.items[index];
+
+            item_paths[index].hash(&mut hasher);
+            item.parent
+                .map(|parent| &item_paths[parent])
+                .hash(&mut hasher);
+            item.permission.hash(&mut hasher);
+            item.owner.hash(&mut hasher);
+            item.path_type.hash(&mut hasher);
+            item.deferred.hash(&mut hasher);
+            item.order.hash(&mut hasher);
+            item.description.hash(&mut hasher);
+
+            for (key, value) in sorted(&item.metadata) {
+                key.hash(&mut hasher);
+                hash_metadata_value(value, &mut hasher);
+            }
+        }
+
+        for (name, fields) in sorted(&self.profiles) {
+            name.hash(&mut hasher);
+            sorted(fields).hash(&mut hasher);
+        }
+
+        let mut secret_fields = self.secret_fields.iter().collect::<Vec<_>>();
+        secret_fields.sort();
+        secret_fields.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// The possible mistakes in the config that were found when it was built.
+    ///
+    /// The warnings do not stop the config from building, but they are likely to be config
+    /// errors. The warnings are sorted by key.
+    pub fn warnings(&self) -> &[ConfigWarning] {
+        &self.warnings
+    }
+
+    /// Add the config's field defaults to the fields, for any field that is not supplied.
+    ///
+    /// The fields are only copied if a default is missing from them.
+    pub(crate) fn with_field_defaults<'a>(
+        &self,
+        fields: &'a crate::types::PathAttributes,
+    ) -> std::borrow::Cow<'a, crate::types::PathAttributes> {
+        if self
+            .field_defaults
+            .keys()
+            .all(|key| fields.contains_key(key))
+        {
+            return std::borrow::Cow::Borrowed(fields);
+        }
+
+        let mut merged_fields = self.field_defaults.clone();
+        merged_fields.extend(
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_owned(), value.to_owned())),
+        );
+
+        std::borrow::Cow::Owned(merged_fields)
+    }
+
+    /// Whether the value is valid for the key's resolver.
+    ///
+    /// The value is drawn the same way as it would be in a path, and then checked against the
+    /// resolver's pattern. For example, a string is never valid for an integer resolver, and a
+    /// string must match the string resolver's pattern if it has one. Keys without a resolver
+    /// accept any non-empty string.
+    pub fn is_valid_value(&self, key: &FieldKey, value: &crate::PathValue) -> bool {
+        let resolver = match self.resolvers.get(key) {
+            Some(resolver) => resolver,
+            None => &Resolver::Default,
         };
+        let tokens = Tokens {
+            tokens: vec![Token::Variable {
+                key: key.to_owned(),
+                default: None,
+            }],
+        };
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert(key.to_owned(), value.to_owned());
 
-        let mut items: Vec<&PathItem> = Vec::new();
-        let mut queue = std::collections::VecDeque::new();
-        queue.push_back(last_id);
+        let mut drawn = String::new();
+
+        if tokens.draw(&mut drawn, &fields, &self.resolvers).is_err() {
+            return false;
+        }
+
+        resolver.is_match(&drawn).unwrap_or(false)
+    }
+
+    /// Parse a value as it would appear in a path into a path value with the key's resolver.
+    ///
+    /// This is the reverse of [PathValue::to_drawn_string](crate::PathValue::to_drawn_string). For
+    /// example, `007` is parsed into the integer `7` for an integer resolver.
+    ///
+    /// # Errors
+    ///
+    /// - The value must match the key's resolver.
+    pub fn parse_field(
+        &self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+        value: &str,
+    ) -> Result<crate::PathValue, crate::Error> {
+        let key = key.try_into()?;
+        let resolver = match self.resolvers.get(&key) {
+            Some(resolver) => resolver,
+            None => &Resolver::Default,
+        };
 
-        while !queue.is_empty() {
-            let item_id = queue.pop_front().unwrap();
-            let item = &self.items[item_id];
-            items.push(item);
+        if !resolver.is_match(value)? {
+            return Err(crate::Error::new(format!(
+                "Value {value:?} is invalid for key: {key}"
+            )));
+        }
+
+        resolver.to_path_value(value)
+    }
+
+    /// Count the directories, files, and file templates that
+    /// `create_workspace` would create with the path fields.
+    ///
+    /// This uses [get_workspace](crate::get_workspace), so it has the same rules for which paths
+    /// are included.
+    pub fn workspace_summary(
+        &self,
+        path_fields: &crate::types::PathAttributes,
+    ) -> Result<crate::WorkspaceSummary, crate::Error> {
+        let mut summary = crate::WorkspaceSummary::default();
+
+        for item in crate::get_workspace(self, path_fields)? {
+            match item.path_type() {
+                crate::PathType::Directory => summary.dirs += 1,
+                crate::PathType::File => summary.files += 1,
+                crate::PathType::FileTemplate => summary.templates += 1,
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Get the path fields seeded with the profile's default fields.
+    ///
+    /// The input fields take priority over the profile's fields. If there is no profile, then the
+    /// input fields are returned as is.
+    ///
+    /// # Errors
+    ///
+    /// - The profile must be added to the config with
+    ///   [ConfigBuilder::add_profile](crate::ConfigBuilder::add_profile).
+    pub fn profile_fields(
+        &self,
+        profile: Option<&str>,
+        fields: &crate::types::PathAttributes,
+    ) -> Result<crate::types::PathAttributes, crate::Error> {
+        let profile = match profile {
+            Some(profile) => profile,
+            None => return Ok(fields.clone()),
+        };
+        let mut profile_fields = match self.profiles.get(profile) {
+            Some(profile_fields) => profile_fields.clone(),
+            None => {
+                return Err(crate::Error::new(format!(
+                    "Could not find profile: {profile}"
+                )));
+            }
+        };
+
+        profile_fields.extend(
+            fields
+                .iter()
+                .map(|(key, value)| (key.to_owned(), value.to_owned())),
+        );
+
+        Ok(profile_fields)
+    }
+
+    /// Get the anchored regex patterns that [get_fields](crate::get_fields) uses for each path
+    /// component of the key.
+    ///
+    /// This is a debugging aid for finding out why a path does not match a key.
+    pub fn debug_pattern(
+        &self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+    ) -> Result<Vec<String>, crate::Error> {
+        let key = key.try_into()?;
+        let item = match self.get_item(&key) {
+            Some(item) => item,
+            None => {
+                return Err(crate::Error::new(format!(
+                    "Could not find pattern from key: {key}"
+                )));
+            }
+        };
+
+        Ok(item
+            .iter()
+            .map(|part| format!("^{}$", part.pattern))
+            .collect())
+    }
+
+    /// Draw the key's path as a glob for shell tools, such as `ls` or `find`.
+    ///
+    /// The path is drawn with the native separator. The fields that are supplied are drawn as
+    /// their values, and the missing fields become `*`. The glob metacharacters `*`, `?`, `[`, and
+    /// `]` in the literal parts of the path and the field values are escaped, such as `[[]`, so a
+    /// folder named `[old]` does not become a character class.
+    ///
+    /// # Errors
+    ///
+    /// - The key must be in the config.
+    /// - The fields must be valid for their resolvers.
+    #[cfg(feature = "std")]
+    pub fn shell_glob(
+        &self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+        fields: &crate::types::PathAttributes,
+    ) -> Result<String, crate::Error> {
+        let key = key.try_into()?;
+        let item = match self.get_item(&key) {
+            Some(item) => item,
+            None => {
+                return Err(crate::Error::new(format!(
+                    "Could not find glob from key: {key}"
+                )));
+            }
+        };
+        let mut glob = String::new();
+
+        for part in item.iter() {
+            if !glob.is_empty() && !glob.ends_with(std::path::MAIN_SEPARATOR) {
+                glob.push(std::path::MAIN_SEPARATOR);
+            }
+
+            part.path
+                .try_to_literal_token(fields, &self.resolvers)?
+                .draw_glob_pattern(&mut glob)?;
+        }
+
+        Ok(glob)
+    }
+
+    /// Find the existing paths under the root that would no longer be managed if the key was
+    /// removed from the config.
+    ///
+    /// This is [find_paths](crate::find_paths) for the key, limited to the paths under the root.
+    /// The root is matched against the top of the key's path, and the search starts at the root,
+    /// so the rest of the filesystem is not walked. It is useful for schema migrations, so the
+    /// migration tool can report what would be orphaned before the item is removed.
+    ///
+    /// # Errors
+    ///
+    /// - The same errors as [find_paths](crate::find_paths).
+    #[cfg(feature = "std")]
+    pub fn orphans_if_removed(
+        &self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+        root: impl AsRef<std::path::Path>,
+        fields: &crate::types::PathAttributes,
+    ) -> Result<Vec<std::path::PathBuf>, crate::Error> {
+        crate::path_resolver::find_paths_under(self, &key.try_into()?, fields, root.as_ref())
+    }
+
+    /// Get the description of the key's path item, if it has one.
+    ///
+    /// # Errors
+    ///
+    /// - The key must be a path item in the config.
+    pub fn description_for(
+        &self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+    ) -> Result<Option<&str>, crate::Error> {
+        let key = key.try_into()?;
+
+        match self.item_map.get(&key) {
+            Some(index) => Ok(self.items[*index].description.as_deref()),
+            None => Err(crate::Error::new(format!(
+                "Could not find path from key: {key}"
+            ))),
+        }
+    }
+
+    /// Whether the key's path will be absolute once it is resolved.
+    ///
+    /// The path is absolute if the root-most path component starts with a separator, such as
+    /// `/proj`, or a drive, such as `C:\proj`. No fields are required, so a path that starts with
+    /// a placeholder, such as `{root}/proj`, is relative even if the root field will be absolute.
+    pub fn is_absolute(
+        &self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+    ) -> Result<bool, crate::Error> {
+        let key = key.try_into()?;
+        let item = match self.get_item(&key) {
+            Some(item) => item,
+            None => {
+                return Err(crate::Error::new(format!(
+                    "Could not find path from key: {key}"
+                )));
+            }
+        };
+
+        let literal = match item.first().and_then(|part| part.path.tokens.first()) {
+            Some(Token::Literal(literal)) => literal,
+            _ => return Ok(false),
+        };
+        let mut characters = literal.chars();
+
+        Ok(match (characters.next(), characters.next()) {
+            (Some('/' | '\\'), _) => true,
+            (Some(drive), Some(':')) => drive.is_ascii_alphabetic(),
+            _ => false,
+        })
+    }
+
+    /// Find the first key whose path shape matches the input path.
+    ///
+    /// No fields are required, since every placeholder is matched by its resolver's pattern. The
+    /// keys are tested in sorted order, so the result is stable if more than one key matches. If
+    /// the path does not conform to any item in the config, then `None` is returned.
+    pub fn conforms(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Option<FieldKey>, crate::Error> {
+        let path = path.as_ref();
+        let path_len = path.iter().count();
+        let mut keys: Vec<&FieldKey> = self.item_map.keys().collect();
+        keys.sort();
+
+        for key in keys {
+            let item_len = match self.get_item(key) {
+                Some(item) => item.len(),
+                None => continue,
+            };
+
+            // The item must have the same depth, otherwise a parent path would match its children.
+            if item_len == path_len && crate::get_fields(self, key, path)?.is_some() {
+                return Ok(Some(key.to_owned()));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Find the fields that differ between two paths of the same key.
+    ///
+    /// The fields are extracted from both paths with [get_fields](crate::get_fields), and the
+    /// fields with different values are returned as the key, the value from the first path, and
+    /// the value from the second path, sorted by the key. For example, two versions of the same
+    /// shot only differ by the version field. An optional extension that is missing from one of the
+    /// paths is compared as an empty string, the same as it is drawn.
+    ///
+    /// # Errors
+    ///
+    /// - The key needs to be in the config.
+    /// - Both paths must match the key.
+    pub fn field_diff(
+        &self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+        path_a: impl AsRef<std::path::Path>,
+        path_b: impl AsRef<std::path::Path>,
+    ) -> Result<Vec<(FieldKey, crate::PathValue, crate::PathValue)>, crate::Error> {
+        let key = key.try_into()?;
+        let mut fields_a = require_fields(self, &key, path_a.as_ref())?;
+        let mut fields_b = require_fields(self, &key, path_b.as_ref())?;
+
+        let mut field_keys = fields_a
+            .keys()
+            .chain(fields_b.keys())
+            .cloned()
+            .collect::<Vec<_>>();
+        field_keys.sort();
+        field_keys.dedup();
+
+        Ok(field_keys
+            .into_iter()
+            .filter_map(|field| {
+                let value_a = fields_a.remove(&field).unwrap_or_else(|| "".into());
+                let value_b = fields_b.remove(&field).unwrap_or_else(|| "".into());
+
+                (value_a != value_b).then_some((field, value_a, value_b))
+            })
+            .collect())
+    }
+
+    /// Check that the fields survive a round trip through the key's path.
+    ///
+    /// The path is resolved with [get_path](crate::get_path), and then the fields are extracted
+    /// again with [get_fields](crate::get_fields). This is useful as a CI guard for schema
+    /// authors, since placeholders that are next to each other can split a drawn path differently
+    /// than the fields that drew it. For example, `{name}_{shot}` with the name `a_b` extracts the
+    /// name `a`.
+    ///
+    /// # Errors
+    ///
+    /// - The same errors as [get_path](crate::get_path).
+    /// - The resolved path must match the key.
+    /// - Every extracted field must equal the input field, or its default if the field was not
+    ///   supplied. The error lists every field that did not round trip.
+    pub fn self_check(
+        &self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+        fields: &crate::types::PathAttributes,
+    ) -> Result<(), crate::Error> {
+        let key = key.try_into()?;
+        let path = crate::get_path(self, &key, fields)?;
+        let extracted = match crate::get_fields(self, &key, &path)? {
+            Some(extracted) => extracted,
+            None => {
+                return Err(crate::Error::new(format!(
+                    "The path {path:?} does not match the key {:?} that resolved it.",
+                    key.as_str()
+                )));
+            }
+        };
+        let fields = self.with_field_defaults(fields);
+
+        let mut mismatches = extracted
+            .iter()
+            .filter_map(|(field, value)| match fields.get(field) {
+                Some(expected) if expected != value => Some((field, expected, value)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if mismatches.is_empty() {
+            return Ok(());
+        }
+
+        mismatches.sort_by_key(|(field, _, _)| *field);
 
-            if let Some(parent_id) = item.parent {
-                queue.push_back(parent_id);
+        Err(crate::Error::new(format!(
+            "The fields for {:?} did not round trip through {path:?}: {}.",
+            key.as_str(),
+            mismatches
+                .iter()
+                .map(|(field, expected, value)| format!(
+                    "{:?} was {expected:?} but extracted as {value:?}",
+                    field.as_str()
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )))
+    }
+}
+
+/// Extract the fields of a path that must match the key.
+fn require_fields(
+    config: &Config,
+    key: &FieldKey,
+    path: &std::path::Path,
+) -> Result<crate::types::PathAttributes, crate::Error> {
+    match crate::get_fields(config, key, path)? {
+        Some(fields) => Ok(fields),
+        None => Err(crate::Error::new(format!(
+            "The path {path:?} does not match the key {:?}.",
+            key.as_str()
+        ))),
+    }
+}
+
+/// Hash a metadata value, with the object keys sorted and the floats hashed by their bits.
+fn hash_metadata_value<H: std::hash::Hasher>(value: &crate::MetadataValue, state: &mut H) {
+    use std::hash::Hash;
+
+    std::mem::discriminant(value).hash(state);
+
+    match value {
+        crate::MetadataValue::None => (),
+        crate::MetadataValue::Bool(value) => value.hash(state),
+        crate::MetadataValue::Integer(value) => value.hash(state),
+        crate::MetadataValue::Float(value) => value.to_bits().hash(state),
+        crate::MetadataValue::String(value) => value.hash(state),
+        crate::MetadataValue::Array(values) => {
+            values.len().hash(state);
+
+            for value in values {
+                hash_metadata_value(value, state);
             }
         }
+        crate::MetadataValue::Object(values) => {
+            let mut values = values.iter().collect::<Vec<_>>();
+            values.sort_by_key(|(key, _)| *key);
+            values.len().hash(state);
 
-        Some(items.iter().rev().copied().collect())
+            for (key, value) in values {
+                key.hash(state);
+                hash_metadata_value(value, state);
+            }
+        }
     }
 }
 
 /// Build a config.
 ///
 /// This will build a config from the input resolvers and then validate and output the config.
-#[derive(Debug, Default)]
+/// The builder can be cloned to fork a base config into variants, such as per-show tweaks.
+#[derive(Debug, Clone, Default)]
 pub struct ConfigBuilder {
     resolvers: Resolvers,
     items: std::collections::HashMap<FieldKey, PathItemArgs>,
+    composites: std::collections::HashMap<FieldKey, Vec<FieldKey>>,
+    profiles: std::collections::HashMap<String, crate::types::PathAttributes>,
+    secret_fields: std::collections::HashSet<FieldKey>,
+    field_defaults: crate::types::PathAttributes,
+    forbidden_fields: std::collections::HashSet<FieldKey>,
+    target_os: Option<crate::TargetOs>,
 }
 
 impl ConfigBuilder {
@@ -52,6 +875,53 @@ impl ConfigBuilder {
         Self {
             resolvers: std::collections::HashMap::new(),
             items: std::collections::HashMap::new(),
+            composites: std::collections::HashMap::new(),
+            profiles: std::collections::HashMap::new(),
+            secret_fields: std::collections::HashSet::new(),
+            field_defaults: crate::types::PathAttributes::new(),
+            forbidden_fields: std::collections::HashSet::new(),
+            target_os: None,
+        }
+    }
+
+    fn check_forbidden_fields(&self) -> Result<(), crate::Error> {
+        if self.forbidden_fields.is_empty() {
+            return Ok(());
+        }
+
+        let mut keys = self
+            .resolvers
+            .keys()
+            .chain(self.items.keys())
+            .chain(self.composites.keys())
+            .collect::<Vec<_>>();
+        // An invalid path is reported later in the build, so only the placeholders that parse are
+        // checked here.
+        let tokens = self
+            .items
+            .values()
+            .filter_map(|args| Tokens::new(&args.path.to_string_lossy()).ok())
+            .collect::<Vec<_>>();
+
+        for token in tokens.iter().flat_map(|tokens| tokens.tokens.iter()) {
+            match token {
+                Token::Variable { key, .. } | Token::OptionalExtension { key } => keys.push(key),
+                Token::Literal(_) | Token::CatchAll => (),
+            }
+        }
+
+        // Sort the keys, so the same field is reported every time.
+        keys.sort();
+
+        match keys
+            .into_iter()
+            .find(|key| self.forbidden_fields.contains(*key))
+        {
+            Some(key) => Err(crate::Error::new(format!(
+                "The field {:?} is forbidden.",
+                key.as_str()
+            ))),
+            None => Ok(()),
         }
     }
 
@@ -68,43 +938,363 @@ impl ConfigBuilder {
     ///   more specific.
     /// - It must not use capturing groups. The internal regex may create capture groups when
     ///   extracting the field values from paths.
+    ///
+    /// # Errors
+    ///
+    /// - The pattern must be a valid regex.
+    /// - The compiled pattern must not be larger than 1 MiB, such as a pattern with a huge
+    ///   repetition like `a{1000000}`.
     pub fn add_string_resolver(
         mut self,
         key: impl TryInto<crate::FieldKey, Error = crate::Error>,
         pattern: Option<&str>,
     ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
         self.resolvers.insert(
-            key.try_into()?,
+            key,
             Resolver::String {
                 pattern: match pattern {
-                    Some(pattern) => Some(crate::cache::regex(pattern)?),
+                    Some(pattern) => Some(crate::types::resolver::compile_pattern(pattern)?),
                     None => None,
                 },
+                percent_encoded: false,
             },
         );
         Ok(self)
     }
 
-    /// Add an integer resolver.
+    /// Add a string resolver for percent-encoded values.
     ///
-    /// Integer resolvers will create integers with zero padding. When the integers are being
-    /// extracted from a path, then only numbers with a minimum number of characters based on the
-    /// padding are considered valid. For example, if the padding is 3 then `1` and `12` are
-    /// invalid, but `001`, `012`, `123`, and `1234` are valid.
-    pub fn add_integer_resolver(
+    /// This is the same as [add_string_resolver](Self::add_string_resolver), but the value is
+    /// percent-encoded when drawing a path, such as `%20` for a space, and decoded when the value
+    /// is extracted from a path. The pattern, if specified, must match the encoded value.
+    pub fn add_percent_encoded_string_resolver(
         mut self,
         key: impl TryInto<crate::FieldKey, Error = crate::Error>,
-        padding: u8,
+        pattern: Option<&str>,
     ) -> Result<Self, crate::Error> {
-        self.resolvers
-            .insert(key.try_into()?, Resolver::Integer { padding });
+        let key = key.try_into()?;
+        self.resolvers.insert(
+            key,
+            Resolver::String {
+                pattern: match pattern {
+                    Some(pattern) => Some(crate::types::resolver::compile_pattern(pattern)?),
+                    None => None,
+                },
+                percent_encoded: true,
+            },
+        );
+        Ok(self)
+    }
+
+    /// Add an integer resolver.
+    ///
+    /// Integer resolvers will create integers with zero padding. When the integers are being
+    /// extracted from a path, then only numbers with a minimum number of characters based on the
+    /// padding are considered valid. For example, if the padding is 3 then `1` and `12` are
+    /// invalid, but `001`, `012`, `123`, and `1234` are valid.
+    ///
+    /// A padding of 0 means that there is no minimum width. The integers are drawn without any
+    /// zero padding, and any number of digits are valid, including leading zeros such as `007`.
+    /// This is different from a padding of 1, where `007` is invalid since `7` is drawn as `7`.
+    pub fn add_integer_resolver(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+        padding: u8,
+    ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
+        self.resolvers.insert(
+            key,
+            Resolver::Integer {
+                padding,
+                group_separator: None,
+                min: None,
+                max: None,
+            },
+        );
+        Ok(self)
+    }
+
+    /// Add an integer resolver with a valid range.
+    ///
+    /// This is the same as [add_integer_resolver](Self::add_integer_resolver), but drawing a path
+    /// with an integer outside of the range is an error, such as a version of 0 when the versions
+    /// start at 1. The range is inclusive. A path with an integer outside of the range does not
+    /// match the key, so [get_fields](crate::get_fields) returns `None`, and the path is skipped
+    /// when searching for paths.
+    ///
+    /// # Errors
+    ///
+    /// - The minimum must not be greater than the maximum.
+    pub fn add_integer_resolver_with_bounds(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+        padding: u8,
+        min: u16,
+        max: u16,
+    ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
+
+        if min > max {
+            return Err(crate::Error::new(format!(
+                "The minimum {min} of {:?} is greater than the maximum {max}.",
+                key.as_str()
+            )));
+        }
+
+        self.resolvers.insert(
+            key,
+            Resolver::Integer {
+                padding,
+                group_separator: None,
+                min: Some(min),
+                max: Some(max),
+            },
+        );
+        Ok(self)
+    }
+
+    /// Add an integer resolver that groups the digits.
+    ///
+    /// This is the same as [add_integer_resolver](Self::add_integer_resolver), but a separator is
+    /// inserted between every three digits when drawing the integer, such as `12_345`. When the
+    /// integers are extracted from a path, the digits must be grouped the same way, and the
+    /// separators are removed.
+    pub fn add_grouped_integer_resolver(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+        padding: u8,
+        group_separator: char,
+    ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
+        self.resolvers.insert(
+            key,
+            Resolver::Integer {
+                padding,
+                group_separator: Some(group_separator),
+                min: None,
+                max: None,
+            },
+        );
+        Ok(self)
+    }
+
+    /// Add a float resolver.
+    ///
+    /// Float resolvers draw the float rounded to the precision, which is the number of digits after
+    /// the decimal point. For example, if the precision is 2 then `1.5` is drawn as `1.50`. When
+    /// the floats are being extracted from a path, then the value must have exactly that many
+    /// digits after the decimal point, so `1.5` and `1.500` are invalid for a precision of 2. A
+    /// precision of 0 draws the float without the decimal point.
+    pub fn add_float_resolver(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+        precision: u8,
+    ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
+        self.resolvers.insert(key, Resolver::Float { precision });
+        Ok(self)
+    }
+
+    /// Add a choice resolver.
+    ///
+    /// The value must be one of the options, such as `model`, `rig`, or `anim` for a department.
+    /// Drawing a path with any other value is an error, and only the options are matched when the
+    /// fields are extracted from a path, so an unrelated directory is never captured. The values
+    /// are strings.
+    ///
+    /// # Errors
+    ///
+    /// - There must be at least one option.
+    /// - The options must not be empty strings.
+    pub fn add_choice_resolver(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+        options: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
+        let options = options.into_iter().map(Into::into).collect::<Vec<String>>();
+
+        if options.is_empty() {
+            return Err(crate::Error::new(format!(
+                "The choice resolver for {:?} must have at least one option.",
+                key.as_str()
+            )));
+        }
+
+        if options.iter().any(|option| option.is_empty()) {
+            return Err(crate::Error::new(format!(
+                "The choice resolver for {:?} must not have an empty option.",
+                key.as_str()
+            )));
+        }
+
+        self.resolvers.insert(key, Resolver::Choice { options });
+        Ok(self)
+    }
+
+    /// Add a date resolver.
+    ///
+    /// Date resolvers draw the date with a strftime-style format, such as `%Y_%m_%d` for
+    /// `2024_01_31`. Only `%Y`, `%y`, `%m`, `%d`, `%j`, and `%%` are supported, so the pattern to
+    /// match the dates can be made from the format. A date field can also be passed as a string in
+    /// the same format. When the dates are being extracted from a path, then they are parsed into
+    /// a [PathValue::Date](crate::PathValue::Date).
+    ///
+    /// # Errors
+    ///
+    /// - The format must only use the supported specifiers.
+    /// - The format must contain enough of a date to be parsed back, such as the year, month, and
+    ///   day.
+    #[cfg(feature = "date")]
+    pub fn add_date_resolver(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+        format: impl Into<String>,
+    ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
+        let format = format.into();
+        crate::types::resolver::date_pattern(&format)?;
+
+        // A format without the whole date, such as `%Y_%m`, draws dates that cannot be parsed.
+        let date = chrono::NaiveDate::default();
+        let drawn = crate::types::value::draw_date(&date, &format)?;
+
+        if crate::types::resolver::parse_date(&drawn, &format).ok() != Some(date) {
+            return Err(crate::Error::new(format!(
+                "The date format {format:?} cannot be parsed back into a date."
+            )));
+        }
+
+        self.resolvers.insert(key, Resolver::Date { format });
+        Ok(self)
+    }
+
+    /// Add a numeric string resolver.
+    ///
+    /// The numeric string resolver only matches digits, like an integer resolver without padding,
+    /// but the value is kept as a string instead of being parsed into an integer. This is useful
+    /// for IDs that are too large for a path integer. An integer field is also accepted when
+    /// drawing a path.
+    pub fn add_numeric_string_resolver(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
+        self.resolvers.insert(key, Resolver::NumericString);
+        Ok(self)
+    }
+
+    /// Add several resolvers at once.
+    ///
+    /// Each spec is registered with the matching `add_*_resolver` method, so a later spec with the
+    /// same key replaces an earlier one.
+    ///
+    /// # Errors
+    ///
+    /// - A string resolver's pattern must be a valid regex.
+    /// - A choice resolver must have at least one option.
+    /// - A bounded integer resolver's minimum must not be greater than its maximum.
+    pub fn add_resolvers(
+        self,
+        specs: impl IntoIterator<Item = ResolverSpec>,
+    ) -> Result<Self, crate::Error> {
+        specs
+            .into_iter()
+            .try_fold(self, |builder, spec| match spec {
+                ResolverSpec::String { key, pattern } => {
+                    builder.add_string_resolver(&key, pattern.as_deref())
+                }
+                ResolverSpec::PercentEncodedString { key, pattern } => {
+                    builder.add_percent_encoded_string_resolver(&key, pattern.as_deref())
+                }
+                ResolverSpec::Integer { key, padding } => {
+                    builder.add_integer_resolver(&key, padding)
+                }
+                ResolverSpec::GroupedInteger {
+                    key,
+                    padding,
+                    group_separator,
+                } => builder.add_grouped_integer_resolver(&key, padding, group_separator),
+                ResolverSpec::BoundedInteger {
+                    key,
+                    padding,
+                    min,
+                    max,
+                } => builder.add_integer_resolver_with_bounds(&key, padding, min, max),
+                ResolverSpec::Float { key, precision } => {
+                    builder.add_float_resolver(&key, precision)
+                }
+                ResolverSpec::Choice { key, options } => builder.add_choice_resolver(&key, options),
+                #[cfg(feature = "date")]
+                ResolverSpec::Date { key, format } => builder.add_date_resolver(&key, format),
+                ResolverSpec::NumericString { key } => builder.add_numeric_string_resolver(&key),
+            })
+    }
+
+    /// Mark a field as secret.
+    ///
+    /// The value of a secret field is still used to resolve paths, but it is replaced with `***`
+    /// in the [Debug] output of the [ResolvedPathItem](crate::ResolvedPathItem), so it does not
+    /// leak into logs.
+    pub fn mark_field_secret(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    ) -> Result<Self, crate::Error> {
+        self.secret_fields.insert(key.try_into()?);
+        Ok(self)
+    }
+
+    /// Forbid a field name.
+    ///
+    /// This is useful for names that are reserved for internal use, or that clash with other keys,
+    /// such as `entity`. Building a config with a resolver, path item, or composite item with the key,
+    /// or a path item with a placeholder for the field, will fail. The final items are checked, so
+    /// the items from [merge](Self::merge) or [replace_path_item](Self::replace_path_item), and the
+    /// items added before the field was forbidden, are checked too.
+    pub fn forbid_field(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    ) -> Result<Self, crate::Error> {
+        self.forbidden_fields.insert(key.try_into()?);
+        Ok(self)
+    }
+
+    /// Set the config wide default for a field.
+    ///
+    /// The default is used by [get_path](crate::get_path) and [get_workspace](crate::get_workspace)
+    /// when the field is not supplied, such as `ext` defaulting to `exr` everywhere. The supplied
+    /// fields, and a profile's fields, take priority over the default. The default is used as if
+    /// it was supplied, so it also takes priority over a placeholder default, such as `{ext|tif}`.
+    pub fn set_field_default(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+        value: crate::PathValue,
+    ) -> Result<Self, crate::Error> {
+        self.field_defaults.insert(key.try_into()?, value);
         Ok(self)
     }
 
+    /// Set the operating system that the paths from [get_path](crate::get_path) must be valid on.
+    ///
+    /// The names drawn from the fields are then validated, so a field value such as `a:b` or
+    /// `CON` fails for [TargetOs::Windows](crate::TargetOs::Windows) instead of producing a path
+    /// that cannot be created. The literal parts of the config are not validated. By default, the
+    /// names are not validated.
+    pub fn set_target_os(mut self, target_os: crate::TargetOs) -> Self {
+        self.target_os = Some(target_os);
+        self
+    }
+
     /// Add a path item.
     ///
     /// Path items are parts of paths that are either fully resolved (contain no placeholders), or
     /// partially resolved (contains placeholders). See [PathItemArgs](crate::PathItemArgs) for more information.
+    ///
+    /// # Errors
+    ///
+    /// - The key must not already be in the path items. Use
+    ///   [replace_path_item](Self::replace_path_item) to intentionally overwrite an existing item.
     pub fn add_path_item(mut self, args: crate::PathItemArgs) -> Result<Self, crate::Error> {
         if self.items.contains_key(&args.key) {
             return Err(crate::Error::new(format!(
@@ -117,6 +1307,143 @@ impl ConfigBuilder {
         Ok(self)
     }
 
+    /// Add a path item, overwriting any existing path item with the same key.
+    ///
+    /// This is useful for layered configs, where a later layer is expected to override an item
+    /// from an earlier layer.
+    pub fn replace_path_item(mut self, args: crate::PathItemArgs) -> Self {
+        self.items.insert(args.key.clone(), args);
+        self
+    }
+
+    /// Add a composite item, which joins the paths of other path items.
+    ///
+    /// The composite item does not have a path of its own. Instead, the path is the path of each
+    /// part joined in order, and the same fields are used to resolve every part. This is useful
+    /// when a path is made of a shared prefix item and a suffix item that are not parent and child.
+    /// The parts after the first should be relative, and the composite item can be used with
+    /// [get_path](crate::get_path) and [get_fields](crate::get_fields), but is not created by
+    /// `create_workspace`.
+    ///
+    /// # Errors
+    ///
+    /// - The key must not already be a path item or a composite item.
+    pub fn add_composite_item(
+        mut self,
+        key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+        parts: Vec<FieldKey>,
+    ) -> Result<Self, crate::Error> {
+        let key = key.try_into()?;
+
+        if self.items.contains_key(&key) || self.composites.contains_key(&key) {
+            return Err(crate::Error::new(format!(
+                "'{}' already in path items.",
+                key
+            )));
+        }
+
+        self.composites.insert(key, parts);
+        Ok(self)
+    }
+
+    /// Add a named profile with default path fields.
+    ///
+    /// A profile is useful for sharing one config between environments, such as a `dev` and `prod`
+    /// profile with a different `root`. The profile's fields are used when the caller does not
+    /// supply the same field. See [Config::profile_fields].
+    pub fn add_profile(
+        mut self,
+        name: impl Into<String>,
+        fields: crate::types::PathAttributes,
+    ) -> Self {
+        self.profiles.insert(name.into(), fields);
+        self
+    }
+
+    /// Merge another builder on top of this one.
+    ///
+    /// This is useful for layered configs, such as a studio config with a project config on top.
+    /// When both builders define the same path item, resolver, composite item, profile, or field
+    /// default, the other builder's definition wins. Secret fields from both builders are kept. Use
+    /// [ConfigBuilder::merge_reporting] to find out which definitions were overridden.
+    pub fn merge(self, other: ConfigBuilder) -> Self {
+        self.merge_reporting(other).0
+    }
+
+    /// Merge another builder on top of this one, and report the overridden definitions.
+    ///
+    /// This is the same as [ConfigBuilder::merge], but also returns every key where the other
+    /// builder replaced a definition that was different. Redefining a key with the same definition
+    /// is not a conflict. The conflicts are sorted by kind, and then by key.
+    pub fn merge_reporting(mut self, other: ConfigBuilder) -> (Self, Vec<MergeConflict>) {
+        let mut path_item_conflicts = Vec::new();
+        let mut resolver_conflicts = Vec::new();
+        let mut composite_conflicts = Vec::new();
+        let mut profile_conflicts = Vec::new();
+        let mut field_default_conflicts = Vec::new();
+
+        for (key, resolver) in other.resolvers.into_iter() {
+            if let Some(previous) = self.resolvers.insert(key.clone(), resolver)
+                && Some(&previous) != self.resolvers.get(&key)
+            {
+                resolver_conflicts.push(MergeConflict::Resolver(key));
+            }
+        }
+
+        for (key, item) in other.items.into_iter() {
+            if let Some(previous) = self.items.insert(key.clone(), item)
+                && Some(&previous) != self.items.get(&key)
+            {
+                path_item_conflicts.push(MergeConflict::PathItem(key));
+            }
+        }
+
+        for (key, parts) in other.composites.into_iter() {
+            if let Some(previous) = self.composites.insert(key.clone(), parts)
+                && Some(&previous) != self.composites.get(&key)
+            {
+                composite_conflicts.push(MergeConflict::Composite(key));
+            }
+        }
+
+        for (name, fields) in other.profiles.into_iter() {
+            if let Some(previous) = self.profiles.insert(name.clone(), fields)
+                && Some(&previous) != self.profiles.get(&name)
+            {
+                profile_conflicts.push(MergeConflict::Profile(name));
+            }
+        }
+
+        for (key, value) in other.field_defaults.into_iter() {
+            if let Some(previous) = self.field_defaults.insert(key.clone(), value)
+                && Some(&previous) != self.field_defaults.get(&key)
+            {
+                field_default_conflicts.push(MergeConflict::FieldDefault(key));
+            }
+        }
+
+        self.secret_fields.extend(other.secret_fields);
+        self.forbidden_fields.extend(other.forbidden_fields);
+
+        if other.target_os.is_some() {
+            self.target_os = other.target_os;
+        }
+
+        path_item_conflicts.sort_by(|a, b| a.key().cmp(b.key()));
+        resolver_conflicts.sort_by(|a, b| a.key().cmp(b.key()));
+        composite_conflicts.sort_by(|a, b| a.key().cmp(b.key()));
+        profile_conflicts.sort_by(|a, b| a.key().cmp(b.key()));
+        field_default_conflicts.sort_by(|a, b| a.key().cmp(b.key()));
+
+        let mut conflicts = path_item_conflicts;
+        conflicts.extend(resolver_conflicts);
+        conflicts.extend(composite_conflicts);
+        conflicts.extend(profile_conflicts);
+        conflicts.extend(field_default_conflicts);
+
+        (self, conflicts)
+    }
+
     /// Build the config from the builder.
     ///
     /// # Errors
@@ -125,7 +1452,31 @@ impl ConfigBuilder {
     /// - If a path item defines a parent, the parent must be defined in the current builder.
     /// - If the path parts have placeholders, then the syntax must be correct. However, a
     ///   placeholder does not need to reference a resolver (it will assume a string resolver).
+    /// - A composite item's key must not be a path item key, and every part must be a path item.
+    /// - The catch-all `{**}` must be the whole and last path component.
+    /// - Two path items must not resolve to the same paths, such as `/a/{x}` and `/a/{y}` when both
+    ///   placeholders use the same resolver.
+    /// - The field defaults must be valid values for their resolvers.
+    /// - The resolvers, path items, composite items, and placeholders must not use a forbidden
+    ///   field. See [forbid_field](Self::forbid_field).
     pub fn build(mut self) -> Result<Config, crate::Error> {
+        self.check_forbidden_fields()?;
+
+        for (key, parts) in self.composites.iter() {
+            if self.items.contains_key(key) {
+                return Err(crate::Error::new(format!(
+                    "'{}' already in path items.",
+                    key
+                )));
+            }
+
+            for part in parts.iter() {
+                if !self.items.contains_key(part) {
+                    return Err(crate::Error::new(format!("Missing composite part: {part}")));
+                }
+            }
+        }
+
         // Find items with parents that cause infinite recursion errors.
         let mut queue = std::collections::VecDeque::new();
         let mut visited = std::collections::HashSet::new();
@@ -196,7 +1547,17 @@ impl ConfigBuilder {
         // Convert all of the path parts into full paths
         for (key, item) in self.items.iter() {
             let key = key.to_owned();
-            let path = recursive_build_path(&item.path, &item.parent, &self.items).to_path_buf();
+            let path =
+                normalize_separators(&recursive_build_path(&item.path, &item.parent, &self.items));
+            let path = match metadata_extension(item) {
+                Some(ext) => {
+                    let mut path = path.into_os_string();
+                    path.push(".");
+                    path.push(ext);
+                    path.into()
+                }
+                None => path,
+            };
             key_path_map.insert(key, path);
         }
 
@@ -214,10 +1575,12 @@ impl ConfigBuilder {
             path_metadata_map.insert(
                 &item.path,
                 (
-                    item.permission,
-                    item.owner,
+                    item.permission.clone(),
+                    item.owner.clone(),
                     item.path_type,
                     item.deferred,
+                    item.order,
+                    item.description.clone(),
                     item.metadata.clone(),
                 ),
             );
@@ -236,15 +1599,21 @@ impl ConfigBuilder {
                 Some(name) => name.to_string_lossy(),
                 None => path.to_string_lossy(),
             };
-            parent_path_items.push(PathItem {
-                path: Tokens::new(&name)?,
-                parent: None,
-                permission: crate::Permission::default(),
-                owner: crate::Owner::default(),
-                path_type: crate::PathType::default(),
-                deferred: true,
-                metadata: std::collections::HashMap::new(),
-            });
+            parent_path_items.push((
+                path,
+                PathItem {
+                    path: Tokens::new(&name)?,
+                    parent: None,
+                    permission: crate::Permission::default(),
+                    owner: crate::Owner::default(),
+                    path_type: crate::PathType::default(),
+                    deferred: true,
+                    order: 0,
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                    pattern: String::new(),
+                },
+            ));
 
             let mut path: &std::path::Path = item.path.as_ref();
 
@@ -274,15 +1643,21 @@ impl ConfigBuilder {
                     None => path.to_string_lossy(),
                 };
 
-                parent_path_items.push(PathItem {
-                    path: Tokens::new(&name)?,
-                    parent: None,
-                    permission: crate::Permission::default(),
-                    owner: crate::Owner::default(),
-                    path_type: crate::PathType::default(),
-                    deferred: true,
-                    metadata: std::collections::HashMap::new(),
-                });
+                parent_path_items.push((
+                    path,
+                    PathItem {
+                        path: Tokens::new(&name)?,
+                        parent: None,
+                        permission: crate::Permission::default(),
+                        owner: crate::Owner::default(),
+                        path_type: crate::PathType::default(),
+                        deferred: true,
+                        order: 0,
+                        description: None,
+                        metadata: std::collections::HashMap::new(),
+                        pattern: String::new(),
+                    },
+                ));
 
                 path = parent;
             }
@@ -291,23 +1666,30 @@ impl ConfigBuilder {
                 .entry(None)
                 .or_insert(Vec::new());
 
-            // Add the root most item if it doesn't exist.
-            if !visited_paths.contains(&None) {
+            // Add the root most item if it doesn't exist. A relative path has already added its
+            // root most item, and ends with an empty path here.
+            if path.components().next_back().is_some() && !visited_paths.contains(&Some(path)) {
                 let name = match path.file_name() {
                     Some(name) => name.to_string_lossy(),
                     None => path.to_string_lossy(),
                 };
-                parent_path_items.push(PathItem {
-                    path: Tokens::new(&name)?,
-                    parent: None,
-                    permission: crate::Permission::default(),
-                    owner: crate::Owner::default(),
-                    path_type: crate::PathType::default(),
-                    deferred: true,
-                    metadata: std::collections::HashMap::new(),
-                });
+                parent_path_items.push((
+                    path,
+                    PathItem {
+                        path: Tokens::new(&name)?,
+                        parent: None,
+                        permission: crate::Permission::default(),
+                        owner: crate::Owner::default(),
+                        path_type: crate::PathType::default(),
+                        deferred: true,
+                        order: 0,
+                        description: None,
+                        metadata: std::collections::HashMap::new(),
+                        pattern: String::new(),
+                    },
+                ));
 
-                visited_paths.insert(None);
+                visited_paths.insert(Some(path));
             }
         }
 
@@ -317,19 +1699,20 @@ impl ConfigBuilder {
         let mut item_map: std::collections::HashMap<FieldKey, usize> =
             std::collections::HashMap::new();
 
-        for (parent, parent_items) in parent_resolved_path_items_map.into_iter() {
+        for (parent, mut parent_items) in parent_resolved_path_items_map.into_iter() {
             let parent_id = parent_index_map
                 .get(&parent.map(|p| p.to_path_buf()))
                 .copied();
 
-            for mut parent_item in parent_items {
+            // Sort the siblings, so the item indexes do not depend on the order of the items.
+            parent_items.sort_by_key(|(path, _)| *path);
+
+            for (path, mut parent_item) in parent_items {
                 parent_item.parent = parent_id;
 
-                let path = match parent {
-                    Some(p) => p.to_path_buf(),
-                    None => std::path::PathBuf::new(),
-                }
-                .join(parent_item.path.to_string());
+                // The path is kept as it was written, since a placeholder with a default may be
+                // written in more than one way, such as `{ext=exr}` and `{ext|exr}`.
+                let path = path.to_path_buf();
                 index_path_map.insert(items.len(), path.clone());
                 parent_index_map.insert(Some(path), items.len());
                 items.push(parent_item);
@@ -344,139 +1727,1678 @@ impl ConfigBuilder {
 
         for (index, item) in items.iter_mut().enumerate() {
             if let Some(path) = index_path_map.get(&index)
-                && let Some((permission, owner, path_type, deferred, metadata)) =
+                && let Some((permission, owner, path_type, deferred, order, description, metadata)) =
                     path_metadata_map.remove(path)
             {
                 item.permission = permission;
                 item.owner = owner;
                 item.path_type = path_type;
                 item.deferred = deferred;
+                item.order = order;
+                item.description = description;
                 item.metadata = metadata;
             }
         }
 
-        Ok(Config {
+        let config = Config {
             resolvers: self.resolvers,
             items,
             item_map,
-        })
+            chain_map: std::collections::HashMap::new(),
+            index_key_map: std::collections::HashMap::new(),
+            composite_map: self.composites,
+            profiles: self.profiles,
+            secret_fields: self.secret_fields,
+            field_defaults: self.field_defaults,
+            warnings: Vec::new(),
+            target_os: self.target_os,
+        };
+
+        config.finish()
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::{Owner, PathType, Permission};
+/// A possible mistake in the config. See [Config::warnings].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigWarning {
+    /// The path item is deferred, and none of its descendants are not deferred, so the path will
+    /// never be created by `create_workspace`.
+    DeadDeferredItem(FieldKey),
+}
 
-    use super::*;
+impl ConfigWarning {
+    /// The key of the path item with the warning.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::DeadDeferredItem(key) => key.as_str(),
+        }
+    }
+}
 
-    #[rstest::rstest]
-    #[case("test", None)]
-    #[case("test", Some(r#".+"#))]
-    fn test_config_builder_add_string_resolver_success(
-        #[case] key: &str,
-        #[case] pattern: Option<&str>,
-    ) {
-        ConfigBuilder::new()
+/// A definition that was overridden by [ConfigBuilder::merge_reporting].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeConflict {
+    /// The path item with the key was overridden.
+    PathItem(FieldKey),
+    /// The resolver with the key was overridden.
+    Resolver(FieldKey),
+    /// The composite item with the key was overridden.
+    Composite(FieldKey),
+    /// The profile with the name was overridden.
+    Profile(String),
+    /// The field default with the key was overridden.
+    FieldDefault(FieldKey),
+}
+
+impl MergeConflict {
+    /// The key or profile name that was overridden.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::PathItem(key)
+            | Self::Resolver(key)
+            | Self::Composite(key)
+            | Self::FieldDefault(key) => key.as_str(),
+            Self::Profile(name) => name,
+        }
+    }
+}
+
+/// The extension from the `ext` metadata of a file item, if the item's path does not already
+/// have an extension or an `{ext}` placeholder.
+fn metadata_extension(item: &PathItemArgs) -> Option<&str> {
+    if !matches!(
+        item.path_type,
+        crate::PathType::File | crate::PathType::FileTemplate
+    ) {
+        return None;
+    }
+
+    let ext = match item.metadata.get("ext") {
+        Some(crate::MetadataValue::String(ext)) => ext.trim_start_matches('.'),
+        _ => return None,
+    };
+    let file_name = item.path.file_name()?.to_string_lossy();
+
+    if ext.is_empty() || file_name.contains('.') {
+        return None;
+    }
+
+    // An invalid file name is reported by the build, so it is left as it is.
+    let tokens = Tokens::new(&file_name).ok()?;
+    let has_ext_field = tokens.tokens.iter().any(|token| match token {
+        Token::Variable { key, .. } | Token::OptionalExtension { key } => key.as_str() == "ext",
+        Token::Literal(_) | Token::CatchAll => false,
+    });
+
+    if has_ext_field {
+        return None;
+    }
+
+    Some(ext)
+}
+
+/// Replace `\\` and `/` with the platform separator, and collapse repeated separators into one.
+///
+/// A repeated separator at the start of the path is kept, since it is a UNC or verbatim prefix on
+/// Windows.
+fn normalize_separators(path: &std::path::Path) -> std::path::PathBuf {
+    let path = path.to_string_lossy();
+    let mut normalized = String::with_capacity(path.len());
+    let mut is_prefix = true;
+    let mut last_was_separator = false;
+
+    for character in path.chars() {
+        let is_separator = character == '\\' || character == '/';
+
+        if is_separator && last_was_separator && !is_prefix {
+            continue;
+        }
+
+        if is_separator {
+            normalized.push(std::path::MAIN_SEPARATOR);
+        } else {
+            normalized.push(character);
+            is_prefix = false;
+        }
+
+        last_was_separator = is_separator;
+    }
+
+    std::path::PathBuf::from(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Owner, PathType, Permission};
+
+    use super::*;
+
+    fn path_item_args(key: &str, path: &str, parent: Option<&str>) -> PathItemArgs {
+        PathItemArgs {
+            key: key.try_into().unwrap(),
+            path: path.into(),
+            parent: parent.map(|parent| parent.try_into().unwrap()),
+            permission: Permission::default(),
+            owner: Owner::default(),
+            path_type: PathType::default(),
+            deferred: false,
+            order: 0,
+            description: None,
+            metadata: std::collections::HashMap::new(),
+        }
+    }
+
+    fn serde_test_config() -> Config {
+        ConfigBuilder::new()
+            .add_string_resolver("shot", Some("sh[0-9]+"))
+            .unwrap()
+            .add_integer_resolver_with_bounds("version", 3, 1, 99)
+            .unwrap()
+            .add_path_item(path_item_args("root", "/mnt/proj", None))
+            .unwrap()
+            .add_path_item(path_item_args("shot", "shots/{shot}", Some("root")))
+            .unwrap()
+            .add_path_item(path_item_args(
+                "publish",
+                "publish/v{version}",
+                Some("shot"),
+            ))
+            .unwrap()
+            .add_path_item(path_item_args("file", "{name|comp}{?.ext}", None))
+            .unwrap()
+            .add_composite_item(
+                "publish_file",
+                vec!["publish".try_into().unwrap(), "file".try_into().unwrap()],
+            )
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    #[rstest::rstest]
+    #[case("publish", &[("shot", "sh010"), ("version", "3")], Ok("/mnt/proj/shots/sh010/publish/v003"))]
+    #[case("publish_file", &[("shot", "sh010"), ("version", "3"), ("ext", "exr")], Ok("/mnt/proj/shots/sh010/publish/v003/comp.exr"))]
+    #[case("shot", &[("shot", "sh020")], Ok("/mnt/proj/shots/sh020"))]
+    #[case("publish", &[("shot", "sh010"), ("version", "100")], Err("Value 100 for \"version\" is out of range. Expected an integer from 1 to 99."))]
+    fn test_config_serde_round_trip(
+        #[case] key: &str,
+        #[case] fields: &[(&str, &str)],
+        #[case] expected: Result<&str, &str>,
+    ) {
+        let config = serde_test_config();
+        let serialized = serde_json::to_string(&config).unwrap();
+        let deserialized: Config = serde_json::from_str(&serialized).unwrap();
+
+        let fields = fields
+            .iter()
+            .map(|(key, value)| {
+                let value = match value.parse::<u16>() {
+                    Ok(value) => crate::PathValue::Integer(value),
+                    Err(_) => crate::PathValue::from(*value),
+                };
+
+                ((*key).try_into().unwrap(), value)
+            })
+            .collect::<crate::types::PathAttributes>();
+
+        let result = crate::get_path(&deserialized, key, &fields);
+
+        match expected {
+            Ok(expected) => {
+                let path = result.unwrap();
+
+                assert_eq!(path, crate::get_path(&config, key, &fields).unwrap());
+                assert_eq!(path, std::path::PathBuf::from(expected));
+                assert_eq!(
+                    crate::get_fields(&deserialized, key, &path).unwrap(),
+                    crate::get_fields(&config, key, &path).unwrap()
+                );
+            }
+            Err(expected) => assert_eq!(result.unwrap_err().to_string(), expected),
+        }
+
+        // The string resolver's pattern survives the round trip.
+        assert!(deserialized.is_valid_value(&"shot".try_into().unwrap(), &"sh010".into()));
+        assert!(!deserialized.is_valid_value(&"shot".try_into().unwrap(), &"abc".into()));
+        assert_eq!(deserialized.warnings(), config.warnings());
+        assert_eq!(deserialized.items.len(), config.items.len());
+        assert_eq!(deserialized.chain_map, config.chain_map);
+    }
+
+    #[rstest::rstest]
+    #[case("parent", serde_json::json!(5), "Infinite recursion error with item 5 and parent 5")]
+    #[case("parent", serde_json::json!(1000), "Missing parent: 1000")]
+    #[case("path", serde_json::json!("{shot"), "Parse Error: Missing closing '}'")]
+    fn test_config_serde_failure(
+        #[case] field: &str,
+        #[case] value: serde_json::Value,
+        #[case] expected: &str,
+    ) {
+        let config = serde_test_config();
+        let mut serialized = serde_json::to_value(&config).unwrap();
+        serialized["items"][5][field] = value;
+
+        let err = serde_json::from_value::<Config>(serialized).unwrap_err();
+
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("test", None)]
+    #[case("test", Some(r#".+"#))]
+    fn test_config_builder_add_string_resolver_success(
+        #[case] key: &str,
+        #[case] pattern: Option<&str>,
+    ) {
+        ConfigBuilder::new()
             .add_string_resolver(key, pattern)
             .unwrap()
             .build()
             .unwrap();
-    }
+    }
+
+    #[test]
+    fn test_config_builder_add_integer_resolver_success() {
+        ConfigBuilder::new()
+            .add_integer_resolver("test", 1)
+            .unwrap()
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_config_builder_add_path_item_success() {
+        ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "path".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_config_builder_add_path_item_with_parent_success() {
+        ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "parent".try_into().unwrap(),
+                path: "/parent/path".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "child".try_into().unwrap(),
+                path: "child/path".into(),
+                parent: Some("parent".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_config_builder_build_parent_with_multiple_children_success() {
+        ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "parent".try_into().unwrap(),
+                path: "/parent/path".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "child1".try_into().unwrap(),
+                path: "child1/path".into(),
+                parent: Some("parent".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "child2".try_into().unwrap(),
+                path: "child2/path".into(),
+                parent: Some("parent".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_config_builder_build_cached_patterns_success() {
+        let config = ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "/mnt/projects.dir/{project}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "publish".try_into().unwrap(),
+                path: "publish/{entity}_v{version}.(final)".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        for item in config.items.iter() {
+            let mut pattern = String::new();
+            item.path
+                .draw_regex_pattern(&mut pattern, &config.resolvers)
+                .unwrap();
+
+            assert!(!item.pattern.is_empty());
+            assert_eq!(item.pattern, pattern);
+        }
+    }
+
+    #[test]
+    fn test_config_builder_add_resolvers_success() {
+        let config = ConfigBuilder::new()
+            .add_resolvers([
+                ResolverSpec::String {
+                    key: "name".try_into().unwrap(),
+                    pattern: Some("[a-z]+?".into()),
+                },
+                ResolverSpec::Integer {
+                    key: "version".try_into().unwrap(),
+                    padding: 3,
+                },
+                ResolverSpec::GroupedInteger {
+                    key: "frame".try_into().unwrap(),
+                    padding: 0,
+                    group_separator: '_',
+                },
+            ])
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(config.resolvers.len(), 3);
+        assert!(matches!(
+            config.resolvers.get(&"name".try_into().unwrap()),
+            Some(Resolver::String { pattern: Some(pattern), .. }) if pattern.as_str() == "[a-z]+?"
+        ));
+        assert!(matches!(
+            config.resolvers.get(&"version".try_into().unwrap()),
+            Some(Resolver::Integer {
+                padding: 3,
+                group_separator: None,
+                min: None,
+                max: None,
+            })
+        ));
+        assert!(matches!(
+            config.resolvers.get(&"frame".try_into().unwrap()),
+            Some(Resolver::Integer {
+                padding: 0,
+                group_separator: Some('_'),
+                min: None,
+                max: None,
+            })
+        ));
+    }
+
+    #[rstest::rstest]
+    #[case(
+        Vec::new(),
+        "The choice resolver for \"dept\" must have at least one option."
+    )]
+    #[case(
+        vec!["model", ""],
+        "The choice resolver for \"dept\" must not have an empty option."
+    )]
+    fn test_config_builder_add_choice_resolver_failure(
+        #[case] options: Vec<&str>,
+        #[case] expected: &str,
+    ) {
+        let err = ConfigBuilder::new()
+            .add_choice_resolver("dept", options)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[cfg(feature = "date")]
+    #[rstest::rstest]
+    #[case(
+        "%Y_%m_%H",
+        "The date format \"%Y_%m_%H\" has an unsupported specifier \"%H\"."
+    )]
+    #[case(
+        "%Y_%m_%",
+        "The date format \"%Y_%m_%\" ends with an incomplete specifier."
+    )]
+    #[case(
+        "%Y_%m",
+        "The date format \"%Y_%m\" cannot be parsed back into a date."
+    )]
+    fn test_config_builder_add_date_resolver_failure(#[case] format: &str, #[case] expected: &str) {
+        let err = ConfigBuilder::new()
+            .add_date_resolver("date", format)
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn test_config_builder_add_integer_resolver_with_bounds_failure() {
+        let err = ConfigBuilder::new()
+            .add_integer_resolver_with_bounds("version", 3, 10, 1)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "The minimum 10 of \"version\" is greater than the maximum 1."
+        );
+    }
+
+    #[test]
+    fn test_config_builder_add_resolvers_failure_invalid_pattern() {
+        let result = ConfigBuilder::new().add_resolvers([ResolverSpec::String {
+            key: "name".try_into().unwrap(),
+            pattern: Some("(".into()),
+        }]);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest::rstest]
+    #[case("version", "007", Ok(7u16.into()))]
+    #[case("frame", "12_345", Ok(12345u16.into()))]
+    #[case("thing", "value", Ok("value".into()))]
+    #[case("version", "abc", Err("Value \"abc\" is invalid for key: version"))]
+    fn test_config_parse_field(
+        #[case] key: &str,
+        #[case] value: &str,
+        #[case] expected: Result<crate::PathValue, &str>,
+    ) {
+        let config = ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_grouped_integer_resolver("frame", 0, '_')
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let result = config.parse_field(key, value);
+
+        match expected {
+            Ok(expected) => {
+                let parsed = result.unwrap();
+                assert_eq!(parsed, expected);
+
+                let resolver = config
+                    .resolvers
+                    .get(&key.try_into().unwrap())
+                    .unwrap_or(&Resolver::Default);
+                assert_eq!(parsed.to_drawn_string(resolver), value);
+            }
+            Err(expected) => assert_eq!(result.unwrap_err().to_string(), expected),
+        }
+    }
+
+    #[test]
+    fn test_config_workspace_summary_success() {
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "{root}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::Directory,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key1".try_into().unwrap(),
+                path: "path/to/{thing}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::Directory,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key2".try_into().unwrap(),
+                path: "path/to/a/{thing}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::Directory,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key3".try_into().unwrap(),
+                path: "path/to/b/{thing}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::Directory,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key4".try_into().unwrap(),
+                path: "path/to/c/{not_specified}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::Directory,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key5".try_into().unwrap(),
+                path: "path/to/d/{some_int}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::Directory,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "file".try_into().unwrap(),
+                path: "path/to/{thing}/notes.txt".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "template".try_into().unwrap(),
+                path: "path/to/{thing}/config.toml".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::FileTemplate,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_integer_resolver("some_int", 3)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("root".try_into().unwrap(), "/tmp/root".into());
+        fields.insert("thing".try_into().unwrap(), "value".into());
+        fields.insert("some_int".try_into().unwrap(), 12u8.into());
+
+        let summary = config.workspace_summary(&fields).unwrap();
+
+        assert_eq!(
+            summary,
+            crate::WorkspaceSummary {
+                dirs: 10,
+                files: 1,
+                templates: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_builder_add_composite_item_success() {
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "base".try_into().unwrap(),
+                path: "/projects/{project}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "task".try_into().unwrap(),
+                path: "tasks/{task}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_composite_item(
+                "base_task",
+                vec!["base".try_into().unwrap(), "task".try_into().unwrap()],
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("project".try_into().unwrap(), "show".into());
+        fields.insert("task".try_into().unwrap(), "comp".into());
+
+        let path = crate::get_path(&config, "base_task", &fields).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/projects/show/tasks/comp"));
+        assert_eq!(
+            crate::get_fields(&config, "base_task", &path).unwrap(),
+            Some(fields)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("base", vec!["task"], "'base' already in path items.")]
+    #[case("base_task", vec!["base", "missing"], "Missing composite part: missing")]
+    fn test_config_builder_add_composite_item_failure(
+        #[case] key: &str,
+        #[case] parts: Vec<&str>,
+        #[case] expected: &str,
+    ) {
+        let result = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "base".try_into().unwrap(),
+                path: "/projects/{project}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "task".try_into().unwrap(),
+                path: "tasks/{task}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_composite_item(
+                key,
+                parts
+                    .into_iter()
+                    .map(|part| part.try_into().unwrap())
+                    .collect(),
+            )
+            .and_then(|builder| builder.build());
+
+        assert_eq!(result.unwrap_err().to_string(), expected);
+    }
+
+    #[test]
+    fn test_config_profile_fields_success() {
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "{root}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "shot".try_into().unwrap(),
+                path: "shots/{shot}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_profile(
+                "dev",
+                [("root".try_into().unwrap(), "/mnt/dev".into())]
+                    .into_iter()
+                    .collect(),
+            )
+            .add_profile(
+                "prod",
+                [("root".try_into().unwrap(), "/mnt/prod".into())]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "sh010".into());
+
+        let dev_path = crate::get_path_with_profile(&config, "shot", &fields, Some("dev")).unwrap();
+        let prod_path =
+            crate::get_path_with_profile(&config, "shot", &fields, Some("prod")).unwrap();
+
+        assert_eq!(dev_path, std::path::PathBuf::from("/mnt/dev/shots/sh010"));
+        assert_eq!(prod_path, std::path::PathBuf::from("/mnt/prod/shots/sh010"));
+
+        fields.insert("root".try_into().unwrap(), "/mnt/local".into());
+        let local_path =
+            crate::get_path_with_profile(&config, "shot", &fields, Some("prod")).unwrap();
+
+        assert_eq!(
+            local_path,
+            std::path::PathBuf::from("/mnt/local/shots/sh010")
+        );
+
+        let err = config.profile_fields(Some("staging"), &fields).unwrap_err();
+
+        assert_eq!(err.to_string(), "Could not find profile: staging");
+    }
+
+    #[test]
+    fn test_config_builder_merge_reporting_success() {
+        fn path_item(key: &str, path: &str) -> PathItemArgs {
+            PathItemArgs {
+                key: key.try_into().unwrap(),
+                path: path.into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            }
+        }
+
+        let base = ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(path_item("root", "/mnt/studio"))
+            .unwrap()
+            .add_path_item(path_item("shot", "/mnt/studio/{shot}"))
+            .unwrap();
+        let layer = ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(path_item("root", "/mnt/project"))
+            .unwrap()
+            .add_path_item(path_item("shot", "/mnt/studio/{shot}"))
+            .unwrap();
+
+        let (builder, conflicts) = base.merge_reporting(layer);
+
+        assert_eq!(
+            conflicts,
+            vec![MergeConflict::PathItem("root".try_into().unwrap())]
+        );
+
+        let config = builder.build().unwrap();
+        let path = crate::get_path(&config, "root", &crate::types::PathAttributes::new()).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/mnt/project"));
+    }
+
+    #[test]
+    fn test_config_debug_pattern_success() {
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let patterns = config.debug_pattern("key").unwrap();
+
+        assert_eq!(patterns, vec![r"^[\\/]$", "^path$", "^to$", "^(.+?)$"]);
+
+        let err = config.debug_pattern("missing").unwrap_err();
+
+        assert_eq!(err.to_string(), "Could not find pattern from key: missing");
+    }
+
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case(&[], "/proj/[[]old[]]/*/*.exr")]
+    #[case(&[("shot", "sh010")], "/proj/[[]old[]]/sh010/*.exr")]
+    #[case(&[("shot", "sh*")], "/proj/[[]old[]]/sh[*]/*.exr")]
+    fn test_config_shell_glob(#[case] fields: &[(&str, &str)], #[case] expected: &str) {
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/proj/[old]/{shot}/{name}.exr".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+        let fields = fields
+            .iter()
+            .map(|(key, value)| ((*key).try_into().unwrap(), (*value).into()))
+            .collect();
+
+        assert_eq!(
+            config.shell_glob("key", &fields).unwrap(),
+            expected.replace('/', std::path::MAIN_SEPARATOR_STR)
+        );
+
+        let err = config.shell_glob("missing", &fields).unwrap_err();
+
+        assert_eq!(err.to_string(), "Could not find glob from key: missing");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_config_orphans_if_removed_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        for path in [
+            "proj_a/shots/sh010",
+            "proj_a/shots/sh020",
+            "proj_b/shots/sh030",
+        ] {
+            std::fs::create_dir_all(root_dir.join(path)).unwrap();
+        }
+
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "shot".try_into().unwrap(),
+                path: root_dir.join("{project}/shots/{shot}"),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let orphans = config
+            .orphans_if_removed(
+                "shot",
+                root_dir.join("proj_a"),
+                &crate::types::PathAttributes::new(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            orphans,
+            vec![
+                root_dir.join("proj_a/shots/sh010"),
+                root_dir.join("proj_a/shots/sh020"),
+            ]
+        );
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("project".try_into().unwrap(), "proj_b".into());
+
+        let orphans = config
+            .orphans_if_removed("shot", root_dir.join("proj_a"), &fields)
+            .unwrap();
+
+        assert!(orphans.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case("proj_a", &["proj_a/shots/sh010", "proj_a/shots/sh020"])]
+    #[case("proj_a/shots/sh010", &["proj_a/shots/sh010"])]
+    #[case("proj_b/shots", &["proj_b/shots/sh030"])]
+    #[case("proj_c", &[])]
+    fn test_config_orphans_if_removed_relative_root(#[case] root: &str, #[case] expected: &[&str]) {
+        // The paths are relative to the current directory, so the test directory is made there.
+        let tmp_dir = tempfile::tempdir_in(".").unwrap();
+        let root_dir = std::path::Path::new(tmp_dir.path().file_name().unwrap());
+
+        for path in [
+            "proj_a/shots/sh010",
+            "proj_a/shots/sh020",
+            "proj_b/shots/sh030",
+        ] {
+            std::fs::create_dir_all(root_dir.join(path)).unwrap();
+        }
+
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "shot".try_into().unwrap(),
+                path: root_dir.join("{project}/shots/{shot}"),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let orphans = config
+            .orphans_if_removed(
+                "shot",
+                root_dir.join(root),
+                &crate::types::PathAttributes::new(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            orphans,
+            expected
+                .iter()
+                .map(|path| root_dir.join(path))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("/proj", true)]
+    #[case("C:/proj", true)]
+    #[case("proj", false)]
+    #[case("{root}/proj", false)]
+    fn test_config_is_absolute(#[case] root: &str, #[case] expected: bool) {
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root.into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "shot".try_into().unwrap(),
+                path: "shots/{shot}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(config.is_absolute("root").unwrap(), expected);
+        assert_eq!(config.is_absolute("shot").unwrap(), expected);
+
+        let err = config.is_absolute("missing").unwrap_err();
+
+        assert_eq!(err.to_string(), "Could not find path from key: missing");
+    }
+
+    #[rstest::rstest]
+    #[case("version", 1u16.into(), true)]
+    #[case("version", 1234u16.into(), true)]
+    #[case("version", "001".into(), false)]
+    #[case("name", "abc".into(), true)]
+    #[case("name", "abc1".into(), false)]
+    #[case("name", 1u16.into(), false)]
+    #[case("other", "abc1".into(), true)]
+    #[case("other", "".into(), false)]
+    fn test_config_is_valid_value(
+        #[case] key: &str,
+        #[case] value: crate::PathValue,
+        #[case] expected: bool,
+    ) {
+        let config = ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_string_resolver("name", Some("[a-z]+"))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let key = key.try_into().unwrap();
+
+        assert_eq!(config.is_valid_value(&key, &value), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("/projects/foo", Some("project"))]
+    #[case("/projects/foo/publish/bar_v001", Some("publish"))]
+    #[case("/projects/foo/publish/bar_vabc", None)]
+    #[case("/projects", None)]
+    #[case("/other/foo", None)]
+    fn test_config_conforms(#[case] path: &str, #[case] expected: Option<&str>) {
+        let config = ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "project".try_into().unwrap(),
+                path: "/projects/{project}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "publish".try_into().unwrap(),
+                path: "publish/{entity}_v{version}".into(),
+                parent: Some("project".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let key = config.conforms(path).unwrap();
+
+        assert_eq!(key.as_ref().map(|key| key.as_str()), expected);
+    }
+
+    #[test]
+    fn test_config_builder_add_path_item_failure_duplicate_key() {
+        let err = ConfigBuilder::new()
+            .add_path_item(path_item_args("key", "path", None))
+            .unwrap()
+            .add_path_item(path_item_args("key", "path", None))
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "'key' already in path items.");
+    }
+
+    #[rstest::rstest]
+    #[case(r"a{1000000}")]
+    #[case(r"\w{100}{100}")]
+    fn test_config_builder_add_string_resolver_failure_pattern_too_large(#[case] pattern: &str) {
+        let err = ConfigBuilder::new()
+            .add_string_resolver("name", Some(pattern))
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "The pattern {pattern:?} is too large. The compiled pattern must be smaller than {} bytes.",
+                crate::types::resolver::PATTERN_SIZE_LIMIT
+            )
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(
+        "/proj/sh010/v001/plate.exr",
+        "/proj/sh010/v002/plate.exr",
+        vec![("version", 1u16.into(), 2u16.into())]
+    )]
+    #[case(
+        "/proj/sh010/v001/plate.exr",
+        "/proj/sh020/v003/plate",
+        vec![
+            ("ext", "exr".into(), "".into()),
+            ("shot", "sh010".into(), "sh020".into()),
+            ("version", 1u16.into(), 3u16.into()),
+        ]
+    )]
+    #[case("/proj/sh010/v001/plate.exr", "/proj/sh010/v001/plate.exr", Vec::new())]
+    fn test_config_field_diff_success(
+        #[case] path_a: &str,
+        #[case] path_b: &str,
+        #[case] expected: Vec<(&str, crate::PathValue, crate::PathValue)>,
+    ) {
+        let config = ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/proj/{shot}/v{version}/{name}{?.ext}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
 
-    #[test]
-    fn test_config_builder_add_integer_resolver_success() {
-        ConfigBuilder::new()
-            .add_integer_resolver("test", 1)
-            .unwrap()
-            .build()
-            .unwrap();
+        let expected = expected
+            .into_iter()
+            .map(|(key, a, b)| (key.try_into().unwrap(), a, b))
+            .collect::<Vec<_>>();
+
+        assert_eq!(config.field_diff("key", path_a, path_b).unwrap(), expected);
     }
 
     #[test]
-    fn test_config_builder_add_path_item_success() {
-        ConfigBuilder::new()
+    fn test_config_field_diff_failure_mismatch() {
+        let config = ConfigBuilder::new()
             .add_path_item(PathItemArgs {
                 key: "key".try_into().unwrap(),
-                path: "path".into(),
+                path: "/proj/{shot}".into(),
                 parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
             .build()
             .unwrap();
+
+        let err = config
+            .field_diff("key", "/proj/sh010", "/other/sh010")
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "The path \"/other/sh010\" does not match the key \"key\"."
+        );
     }
 
     #[test]
-    fn test_config_builder_add_path_item_with_parent_success() {
-        ConfigBuilder::new()
+    fn test_config_self_check_success() {
+        let config = ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
             .add_path_item(PathItemArgs {
-                key: "parent".try_into().unwrap(),
-                path: "/parent/path".into(),
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{name}/v{version}".into(),
                 parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("name".try_into().unwrap(), "a_b".into());
+        fields.insert("version".try_into().unwrap(), 7u16.into());
+
+        config.self_check("key", &fields).unwrap();
+    }
+
+    #[test]
+    fn test_config_self_check_failure_non_greedy_default() {
+        let config = ConfigBuilder::new()
             .add_path_item(PathItemArgs {
-                key: "child".try_into().unwrap(),
-                path: "child/path".into(),
-                parent: Some("parent".try_into().unwrap()),
+                key: "key".try_into().unwrap(),
+                path: "/path/to/{name}_{shot}".into(),
+                parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
             .build()
             .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("name".try_into().unwrap(), "a_b".into());
+        fields.insert("shot".try_into().unwrap(), "c".into());
+
+        let err = config.self_check("key", &fields).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "The fields for \"key\" did not round trip through \"/path/to/a_b_c\": \"name\" was String(\"a_b\") but extracted as String(\"a\"), \"shot\" was String(\"c\") but extracted as String(\"b_c\")."
+        );
     }
 
     #[test]
-    fn test_config_builder_build_parent_with_multiple_children_success() {
-        ConfigBuilder::new()
+    fn test_config_content_hash() {
+        let metadata = std::collections::HashMap::from([(
+            "tags".to_string(),
+            crate::MetadataValue::Object(std::collections::HashMap::from([
+                ("a".to_string(), 1.5.into()),
+                ("b".to_string(), "b".into()),
+            ])),
+        )]);
+        let build = |padding: u8, reversed: bool| {
+            let mut items = vec![
+                PathItemArgs {
+                    metadata: metadata.clone(),
+                    ..path_item_args("shot", "/proj/{shot}", None)
+                },
+                PathItemArgs {
+                    metadata: metadata.clone(),
+                    ..path_item_args("publish", "publish/v{version}", Some("shot"))
+                },
+            ];
+
+            if reversed {
+                items.reverse();
+            }
+
+            items
+                .into_iter()
+                .try_fold(
+                    ConfigBuilder::new()
+                        .add_string_resolver("shot", Some(r"sh\d+?"))
+                        .unwrap()
+                        .add_integer_resolver("version", padding)
+                        .unwrap(),
+                    |builder, args| builder.add_path_item(args),
+                )
+                .unwrap()
+                .build()
+                .unwrap()
+        };
+
+        assert_eq!(
+            build(3, false).content_hash(),
+            build(3, true).content_hash()
+        );
+        assert_ne!(
+            build(3, false).content_hash(),
+            build(4, false).content_hash()
+        );
+    }
+
+    #[test]
+    fn test_config_content_hash_siblings() {
+        let build = || {
+            ["a", "b", "c", "d", "e", "f", "g", "h"]
+                .into_iter()
+                .try_fold(ConfigBuilder::new(), |builder, name| {
+                    builder.add_path_item(path_item_args(name, &format!("/root/{name}"), None))
+                })
+                .unwrap()
+                .build()
+                .unwrap()
+        };
+        let config = build();
+
+        for _ in 0..20 {
+            let other = build();
+
+            assert_eq!(config.content_hash(), other.content_hash());
+            assert_eq!(config.item_map, other.item_map);
+        }
+    }
+
+    #[test]
+    fn test_config_builder_clone_variants() {
+        let base = ConfigBuilder::new()
+            .add_string_resolver("shot", Some(r"sh\d+?"))
+            .unwrap()
+            .add_path_item(path_item_args("shot", "/proj/{shot}", None))
+            .unwrap();
+
+        let variant = base
+            .clone()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(path_item_args(
+                "publish",
+                "publish/v{version}",
+                Some("shot"),
+            ))
+            .unwrap()
+            .build()
+            .unwrap();
+        let base = base.build().unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "sh010".into());
+        fields.insert("version".try_into().unwrap(), 1u16.into());
+
+        assert_eq!(
+            crate::get_path(&base, "shot", &fields).unwrap(),
+            crate::get_path(&variant, "shot", &fields).unwrap()
+        );
+        assert_eq!(
+            crate::get_path(&variant, "publish", &fields).unwrap(),
+            std::path::PathBuf::from("/proj/sh010/publish/v001")
+        );
+        assert!(crate::get_path(&base, "publish", &fields).is_err());
+        assert!(!base.resolvers.contains_key(&"version".try_into().unwrap()));
+    }
+
+    #[test]
+    fn test_config_builder_forbid_field() {
+        let builder = || ConfigBuilder::new().forbid_field("entity").unwrap();
+
+        let err = builder()
+            .add_path_item(path_item_args("shot", "/proj/{entity}/{shot}", None))
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "The field \"entity\" is forbidden.");
+
+        let err = builder()
+            .add_path_item(path_item_args("entity", "/proj", None))
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "The field \"entity\" is forbidden.");
+
+        let err = builder()
+            .add_string_resolver("entity", None)
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "The field \"entity\" is forbidden.");
+
+        // The items that bypass the builder methods, or are added before the field is forbidden,
+        // are checked too.
+        let err = builder()
+            .replace_path_item(path_item_args("shot", "/proj/{entity}", None))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "The field \"entity\" is forbidden.");
+
+        let err = builder()
+            .merge(
+                ConfigBuilder::new()
+                    .add_path_item(path_item_args("shot", "/proj/{entity}", None))
+                    .unwrap(),
+            )
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "The field \"entity\" is forbidden.");
+
+        let err = ConfigBuilder::new()
+            .add_path_item(path_item_args("shot", "/proj/{entity}", None))
+            .unwrap()
+            .forbid_field("entity")
+            .unwrap()
+            .build()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "The field \"entity\" is forbidden.");
+
+        assert!(
+            builder()
+                .add_path_item(path_item_args("shot", "/proj/{shot}", None))
+                .unwrap()
+                .build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_config_builder_build_failure_invalid_field_default() {
+        let err = ConfigBuilder::new()
+            .add_integer_resolver("frame", 4)
+            .unwrap()
+            .set_field_default("frame", "first".into())
+            .unwrap()
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Default String(\"first\") is invalid for \"frame\"."
+        );
+    }
+
+    #[test]
+    fn test_config_warnings_dead_deferred_item() {
+        let config = ConfigBuilder::new()
+            .add_path_item(path_item_args("shot", "/proj/{shot}", None))
+            .unwrap()
             .add_path_item(PathItemArgs {
-                key: "parent".try_into().unwrap(),
-                path: "/parent/path".into(),
+                deferred: true,
+                ..path_item_args("work", "work/{task}", Some("shot"))
+            })
+            .unwrap()
+            .add_path_item(path_item_args("scene", "{scene}.blend", Some("work")))
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                deferred: true,
+                ..path_item_args("cache", "cache/{cache}", Some("shot"))
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                deferred: true,
+                ..path_item_args("sim", "sim", Some("cache"))
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.warnings(),
+            &[
+                ConfigWarning::DeadDeferredItem("cache".try_into().unwrap()),
+                ConfigWarning::DeadDeferredItem("sim".try_into().unwrap()),
+            ]
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("/a/{**}", None)]
+    #[case(
+        "/a/b{**}",
+        Some("The catch-all {**} must be a whole path component, found \"b{**}\".")
+    )]
+    #[case(
+        "/a/{**}/b",
+        Some("The catch-all {**} must be the last path component.")
+    )]
+    fn test_config_builder_build_catch_all(#[case] path: &str, #[case] expected: Option<&str>) {
+        let result = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: path.into(),
                 parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
+            .build();
+
+        assert_eq!(result.err().map(|err| err.to_string()).as_deref(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(
+        None,
+        Some("Path items \"key_x\" and \"key_y\" resolve to the same paths.")
+    )]
+    #[case(Some(4), None)]
+    fn test_config_builder_build_same_paths(
+        #[case] y_padding: Option<u8>,
+        #[case] expected: Option<&str>,
+    ) {
+        let mut builder = ConfigBuilder::new()
+            .add_path_item(path_item_args("key_x", "/a/{x}", None))
+            .unwrap()
+            .add_path_item(path_item_args("key_y", "/a/{y}", None))
+            .unwrap();
+
+        if let Some(padding) = y_padding {
+            builder = builder.add_integer_resolver("y", padding).unwrap();
+        }
+
+        let result = builder.build();
+
+        assert_eq!(result.err().map(|err| err.to_string()).as_deref(), expected);
+    }
+
+    #[test]
+    fn test_config_builder_replace_path_item_success() {
+        let config = ConfigBuilder::new()
             .add_path_item(PathItemArgs {
-                key: "child1".try_into().unwrap(),
-                path: "child1/path".into(),
-                parent: Some("parent".try_into().unwrap()),
+                key: "key".try_into().unwrap(),
+                path: "/first/path".into(),
+                parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
-            .add_path_item(PathItemArgs {
-                key: "child2".try_into().unwrap(),
-                path: "child2/path".into(),
-                parent: Some("parent".try_into().unwrap()),
+            .replace_path_item(PathItemArgs {
+                key: "key".try_into().unwrap(),
+                path: "/second/path".into(),
+                parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
-            .unwrap()
             .build()
             .unwrap();
+
+        let path = crate::get_path(&config, "key", &crate::types::PathAttributes::new()).unwrap();
+
+        assert_eq!(path, std::path::PathBuf::from("/second/path"));
     }
 
     #[test]
@@ -490,6 +3412,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -510,6 +3434,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -521,6 +3447,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -541,6 +3469,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -552,6 +3482,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -577,6 +3509,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -588,6 +3522,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -604,6 +3540,160 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_config_description_for_success() {
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "/projects/{project}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "shot".try_into().unwrap(),
+                path: "shots/{shot}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: Some("The working directory for a shot.".into()),
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.description_for("shot").unwrap(),
+            Some("The working directory for a shot.")
+        );
+        assert_eq!(config.description_for("root").unwrap(), None);
+
+        let err = config.description_for("missing").unwrap_err();
+
+        assert_eq!(err.to_string(), "Could not find path from key: missing");
+    }
+
+    #[test]
+    fn test_config_build_relative_root_success() {
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("root".try_into().unwrap(), "/tmp/root".into());
+        fields.insert("thing".try_into().unwrap(), "value".into());
+
+        // The items are built from hash maps, so build the config a few times to cover different
+        // orders.
+        for _ in 0..10 {
+            let config = ConfigBuilder::new()
+                .add_path_item(path_item_args("root", "{root}", None))
+                .unwrap()
+                .add_path_item(path_item_args("key1", "path/to/{thing}", Some("root")))
+                .unwrap()
+                .add_path_item(path_item_args("key2", "path/to/a/{thing}", Some("root")))
+                .unwrap()
+                .add_path_item(path_item_args("other", "other", None))
+                .unwrap()
+                .build()
+                .unwrap();
+
+            // A relative root item is not under an empty parent path.
+            assert_eq!(
+                config.get_item(&"root".try_into().unwrap()).unwrap().len(),
+                1
+            );
+            assert_eq!(
+                config.get_item(&"other".try_into().unwrap()).unwrap().len(),
+                1
+            );
+
+            let workspace = crate::get_workspace(&config, &fields).unwrap();
+
+            assert!(
+                workspace
+                    .iter()
+                    .all(|item| !item.value().as_os_str().is_empty())
+            );
+            assert_eq!(
+                workspace
+                    .iter()
+                    .map(|item| item.value())
+                    .collect::<Vec<_>>(),
+                vec![
+                    std::path::Path::new("/tmp/root"),
+                    std::path::Path::new("/tmp/root/path"),
+                    std::path::Path::new("/tmp/root/path/to"),
+                    std::path::Path::new("/tmp/root/path/to/a"),
+                    std::path::Path::new("/tmp/root/path/to/a/value"),
+                    std::path::Path::new("/tmp/root/path/to/value"),
+                    std::path::Path::new("other"),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_config_build_chain_map_success() {
+        let config = ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "parent".try_into().unwrap(),
+                path: "/parent/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "child".try_into().unwrap(),
+                path: "child/{other}".into(),
+                parent: Some("parent".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // The cached chain must be the same as walking the parents of the item.
+        for (key, index) in config.item_map.iter() {
+            let mut expected = vec![*index];
+
+            while let Some(parent_index) = config.items[expected[0]].parent {
+                expected.insert(0, parent_index);
+            }
+
+            assert_eq!(config.chain_map.get(key), Some(&expected));
+        }
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("thing".try_into().unwrap(), "a".into());
+        fields.insert("other".try_into().unwrap(), "b".into());
+
+        for _ in 0..2 {
+            assert_eq!(
+                crate::get_path(&config, "child", &fields).unwrap(),
+                std::path::PathBuf::from("/parent/a/child/b")
+            );
+        }
+    }
+
     #[test]
     fn test_config_get_item_metadata_success() {
         let config = ConfigBuilder::new()
@@ -615,6 +3705,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: [("test".to_string(), crate::MetadataValue::Integer(123))]
                     .into_iter()
                     .collect(),
@@ -625,7 +3717,7 @@ mod tests {
 
         let item = config.get_item(&"item".try_into().unwrap()).unwrap();
         assert_eq!(
-            item[1].metadata.get("test"),
+            item[0].metadata.get("test"),
             Some(&crate::MetadataValue::Integer(123))
         );
     }
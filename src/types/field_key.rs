@@ -22,7 +22,9 @@ impl<'de> serde::de::Visitor<'de> for FieldKeyVisitor {
 /// - The first character of the key must be any ASCII alphabetic character or `_`.
 /// - The remainder characters must be any ASCII alphanumeric character or `_`.
 /// - Sections can be split with `.`. The above rules then apply to each section.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// - The key must not be longer than [MAX_LEN](Self::MAX_LEN) characters, or have more than
+///   [MAX_SECTIONS](Self::MAX_SECTIONS) sections.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct FieldKey {
     key: String,
 }
@@ -49,17 +51,20 @@ impl std::fmt::Display for FieldKey {
 }
 
 impl FieldKey {
+    /// The maximum number of characters in a key.
+    pub const MAX_LEN: usize = 256;
+    /// The maximum number of `.` separated sections in a key.
+    pub const MAX_SECTIONS: usize = 32;
+
     /// Create a new field key.
     pub fn new(key: &str) -> Result<Self, crate::Error> {
         let key = key.to_lowercase();
-        let mut parsed_key = String::new();
 
         if !Self::validate(&key) {
             return Err(crate::Error::new("Invalid field key"));
         }
 
-        Self::parse(&key, &mut parsed_key)?;
-        Ok(Self { key: parsed_key })
+        Ok(Self { key })
     }
 
     /// Access the internal key string.
@@ -67,53 +72,20 @@ impl FieldKey {
         &self.key
     }
 
-    fn parse(text: &str, writer: &mut impl std::fmt::Write) -> Result<(), crate::Error> {
-        let split_index = match text.find('.') {
-            Some(index) => index,
-            None => {
-                writer.write_str(text)?;
-
-                return Ok(());
-            }
-        };
-        let (before, after) = text.split_at(split_index);
-        writer.write_str(before)?;
-
-        let after = &after[1..];
-
-        if !after.is_empty() {
-            writer.write_char('.')?;
-            Self::parse(after, writer)?;
-        }
-
-        Ok(())
-    }
-
     pub(crate) fn validate(text: &str) -> bool {
-        if text.is_empty() {
+        // Check the limits before looking at the sections, so huge keys are rejected cheaply.
+        if text.is_empty() || text.len() > Self::MAX_LEN {
             return false;
         }
 
-        let split_index = match text.find('.') {
-            Some(index) => index,
-            None => {
-                if !Self::validate_part(text) {
-                    return false;
-                }
+        let mut section_count = 0;
 
-                return true;
-            }
-        };
-        let (before, after) = text.split_at(split_index);
+        for section in text.split('.') {
+            section_count += 1;
 
-        if !Self::validate_part(before) {
-            return false;
-        }
-
-        let after = &after[1..];
-
-        if !Self::validate(after) {
-            return false;
+            if section_count > Self::MAX_SECTIONS || !Self::validate_part(section) {
+                return false;
+            }
         }
 
         true
@@ -186,6 +158,8 @@ mod tests {
     #[case("abc.def.ghi", "abc.def.ghi")]
     #[case("abc123.def456", "abc123.def456")]
     #[case("_abc._def", "_abc._def")]
+    #[case(&"a".repeat(FieldKey::MAX_LEN), &"a".repeat(FieldKey::MAX_LEN))]
+    #[case(&vec!["a"; FieldKey::MAX_SECTIONS].join("."), &vec!["a"; FieldKey::MAX_SECTIONS].join("."))]
     fn test_field_key_new_success(#[case] input: &str, #[case] expected: &str) {
         // New
         let result = FieldKey::new(input).unwrap();
@@ -221,6 +195,9 @@ mod tests {
     #[case("abc.d!", "Invalid field key")]
     #[case(".", "Invalid field key")]
     #[case("..", "Invalid field key")]
+    #[case(&"a".repeat(FieldKey::MAX_LEN + 1), "Invalid field key")]
+    #[case(&vec!["a"; FieldKey::MAX_SECTIONS + 1].join("."), "Invalid field key")]
+    #[case(&vec!["a"; 10_000].join("."), "Invalid field key")]
     fn test_tokens_parse_failure(#[case] input: &str, #[case] expected: &str) {
         // New
         let result = FieldKey::new(input).unwrap_err();
@@ -280,4 +257,27 @@ mod tests {
 
         assert_ne!(input, other);
     }
+
+    #[test]
+    fn test_field_key_sort() {
+        let mut keys = ["shot", "asset.name", "asset", "_id", "shot10", "shot2"]
+            .into_iter()
+            .map(|key| FieldKey::new(key).unwrap())
+            .collect::<Vec<_>>();
+        keys.sort();
+
+        assert_eq!(
+            keys.iter().map(|key| key.as_str()).collect::<Vec<_>>(),
+            vec!["_id", "asset", "asset.name", "shot", "shot10", "shot2"]
+        );
+    }
+
+    #[test]
+    fn test_field_key_validate_many_sections() {
+        // A huge key must be rejected without recursing per section.
+        let key = vec!["a"; 10_000].join(".");
+
+        assert!(!FieldKey::validate(&key));
+        assert!(crate::types::Tokens::new(&format!("{{{key}}}")).is_err());
+    }
 }
@@ -1,18 +1,30 @@
 mod config;
 mod field_key;
+#[cfg(feature = "std")]
+mod options;
 mod path_item;
-mod resolver;
+pub(crate) mod resolver;
+mod serializer;
+mod target_os;
 mod token;
-mod value;
+pub(crate) mod value;
 
 pub(crate) type PathAttributes = std::collections::HashMap<FieldKey, PathValue>;
 pub(crate) type TemplateAttributes = std::collections::HashMap<FieldKey, TemplateValue>;
+pub(crate) type TemplateObject = indexmap::IndexMap<FieldKey, TemplateValue>;
 pub(crate) type Resolvers = std::collections::HashMap<FieldKey, Resolver>;
 
-pub use config::{Config, ConfigBuilder};
+pub use config::{Config, ConfigBuilder, ConfigWarning, MergeConflict};
 pub use field_key::FieldKey;
+#[cfg(feature = "std")]
+pub use options::{CreateWorkspaceErrorMode, CreateWorkspaceOptions, FindPathsOptions};
 pub(crate) use path_item::PathItem;
-pub use path_item::{Owner, PathItemArgs, PathType, Permission, ResolvedPathItem};
-pub use resolver::Resolver;
+pub use path_item::{
+    Owner, PathItemArgs, PathType, Permission, ResolvedPathItem, WorkspaceNode, WorkspaceSummary,
+};
+pub use resolver::{Resolver, ResolverSpec};
+pub use target_os::TargetOs;
 pub(crate) use token::{Token, Tokens};
-pub use value::{MetadataValue, PathValue, TemplateValue};
+pub use value::{
+    GetFieldsResult, MetadataValue, PathFields, PathSegment, PathValue, TemplateValue,
+};
@@ -0,0 +1,46 @@
+/// Options for [find_paths_with_options](crate::find_paths_with_options).
+#[derive(Debug, Clone, Default)]
+pub struct FindPathsOptions {
+    /// Glob patterns for the names of files and directories to skip while searching, such as
+    /// `__pycache__` or `*.tmp`. If a directory matches, then nothing inside of it is searched.
+    /// The patterns only apply to the components of the path that are searched for, since literal
+    /// components are explicitly part of the config.
+    pub ignore: Vec<String>,
+    /// Return the paths relative to the root item, which is the key's top-most parent, such as
+    /// `path/to/value_001.txt` instead of `/mnt/proj/path/to/value_001.txt`.
+    ///
+    /// The components that matched the root item are stripped from each path, so a root with an
+    /// unresolved placeholder is stripped from each path, whatever value was found. If the key does
+    /// not have a parent, then the paths are relative to the filesystem root.
+    pub relative: bool,
+}
+
+/// How [create_workspace_with_options](crate::create_workspace_with_options) handles errors from
+/// the IO function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CreateWorkspaceErrorMode {
+    /// Stop creating the workspace on the first error, and return the error.
+    #[default]
+    FailFast,
+    /// Attempt to create every path, and return the paths that failed with their errors.
+    ///
+    /// This is useful for batch provisioning where partial success is acceptable. Children of a
+    /// path that failed are still attempted.
+    CollectErrors,
+}
+
+/// Options for [create_workspace_with_options](crate::create_workspace_with_options).
+#[derive(Debug, Clone, Default)]
+pub struct CreateWorkspaceOptions {
+    /// How to handle errors from the IO function. The default is to stop on the first error.
+    pub error_mode: CreateWorkspaceErrorMode,
+    /// The keys that must be created. If the path of any of these keys would be skipped, such as
+    /// because a field is missing, then an error is returned before any IO is done.
+    pub require_keys: Vec<crate::FieldKey>,
+    /// Check that every resolved path exists after the IO function has been called for all of
+    /// them. If any path is missing, such as when the IO function did nothing for the path, then
+    /// an error that lists the missing paths is returned. The paths that the IO function returned
+    /// an error for are not checked, but are listed in the error with their errors, since the
+    /// failures are not returned with it.
+    pub verify: bool,
+}
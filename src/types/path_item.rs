@@ -4,13 +4,28 @@ use crate::types::{FieldKey, Tokens};
 ///
 /// This struct is used in the [ConfigBuilder::add_path_item](crate::ConfigBuilder::add_path_item)
 /// as input arguments.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PathItemArgs {
     /// The name of the field.
     pub key: FieldKey,
     /// The path part that may or may not contain placeholders. For example, `path/to/{item}`. It
     /// is recommended that all path parts are always relative, and to have the root of the path
     /// defined as a placeholder.
+    ///
+    /// A placeholder may have a default after a pipe, such as `{ext|exr}`, or after an equals
+    /// sign, such as `{ext=exr}`. The default is used when the field is not supplied, and must be a
+    /// valid value for the placeholder's resolver.
+    ///
+    /// An optional extension, such as `{name}{?.ext}`, matches both `shot_v001.exr` and
+    /// `shot_v001`. The `ext` field is only extracted when the extension is in the path, and the
+    /// `.` is only drawn when the field is supplied.
+    ///
+    /// The catch-all `{**}` matches every path under its parent, at any depth, when finding paths
+    /// with `find_paths`. It must be the whole and last path component, such
+    /// as `{root}/cache/{**}`, and the path cannot be drawn with [get_path](crate::get_path).
+    ///
+    /// Both `/` and `\\` are treated as separators, and repeated separators such as `path//to` are
+    /// collapsed into one when the config is built.
     pub path: std::path::PathBuf,
     /// The parent path item's field key.
     pub parent: Option<FieldKey>,
@@ -21,15 +36,27 @@ pub struct PathItemArgs {
     /// The path of the path and all child paths unless explicitly set.
     pub path_type: PathType,
     /// Whether the path is deferred or not. If a path is deferred, then the
-    /// [create_workspace][crate::create_workspace] will not resolve the path unless a subpath is
+    /// `create_workspace` will not resolve the path unless a subpath is
     /// resolvable. For example, if the path `path/to/{thing}` is marked as deferred, then even if
     /// the field `thing` is available when resolving the path, the path will not be resolved.
     /// However, if the path has a non-defferred subpath that expands the current path to
     /// `path/to/{thing}/some/{subthing}`, and both thing and subthing are valid, then the path
     /// will be resolved.
     pub deferred: bool,
+    /// The creation order of the path relative to its siblings. When the
+    /// `create_workspace` function creates the paths that share the same
+    /// parent, the paths with a lower order are created before the paths with a higher order. The
+    /// default is `0`.
+    pub order: i32,
+    /// A human readable description of the path, such as for generated documentation or tooltips.
+    /// See [Config::description_for](crate::Config::description_for).
+    pub description: Option<String>,
     /// Extra metadata for the arguments that may be useful, such as marking a path as belonging to
     /// a specific user.
+    ///
+    /// A file or file template item with a string `ext` value, such as `"exr"`, has `.exr` appended
+    /// to its path when the config is built. A literal extension in the path, such as
+    /// `{name}.jpg`, or an `{ext}` placeholder takes precedence, and the metadata is then ignored.
     pub metadata: std::collections::HashMap<String, crate::MetadataValue>,
 }
 
@@ -41,11 +68,18 @@ pub(crate) struct PathItem {
     pub(crate) owner: Owner,
     pub(crate) path_type: PathType,
     pub(crate) deferred: bool,
+    pub(crate) order: i32,
+    pub(crate) description: Option<String>,
     pub(crate) metadata: std::collections::HashMap<String, crate::MetadataValue>,
+    /// The regex pattern for the path component, drawn once when the config is built.
+    pub(crate) pattern: String,
 }
 
 /// The path item that has been validated and resolved in the config.
-#[derive(Debug, Clone)]
+///
+/// The [Debug] output replaces the values of secret fields in the path with `***`. See
+/// [ConfigBuilder::mark_field_secret](crate::ConfigBuilder::mark_field_secret).
+#[derive(Clone)]
 pub struct ResolvedPathItem {
     pub(crate) key: Option<FieldKey>,
     pub(crate) value: std::path::PathBuf,
@@ -53,10 +87,56 @@ pub struct ResolvedPathItem {
     pub(crate) owner: Owner,
     pub(crate) path_type: PathType,
     pub(crate) deferred: bool,
+    pub(crate) order: i32,
     pub(crate) metadata: std::collections::HashMap<String, crate::MetadataValue>,
+    /// The path with the secret field values replaced, if the config has secret fields.
+    pub(crate) redacted_value: Option<std::path::PathBuf>,
+}
+
+impl std::fmt::Debug for ResolvedPathItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResolvedPathItem")
+            .field("key", &self.key)
+            .field("value", self.redacted_value.as_ref().unwrap_or(&self.value))
+            .field("permission", &self.permission)
+            .field("owner", &self.owner)
+            .field("path_type", &self.path_type)
+            .field("deferred", &self.deferred)
+            .field("order", &self.order)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
 }
 
 impl ResolvedPathItem {
+    /// Create a resolved path item directly.
+    ///
+    /// The resolved path items are usually created by [get_workspace](crate::get_workspace), but
+    /// this is useful for testing IO functions without building a config.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        key: Option<FieldKey>,
+        value: impl Into<std::path::PathBuf>,
+        permission: Permission,
+        owner: Owner,
+        path_type: PathType,
+        deferred: bool,
+        order: i32,
+        metadata: std::collections::HashMap<String, crate::MetadataValue>,
+    ) -> Self {
+        Self {
+            key,
+            value: value.into(),
+            permission,
+            owner,
+            path_type,
+            deferred,
+            order,
+            metadata,
+            redacted_value: None,
+        }
+    }
+
     /// The key for the path.
     pub fn key(&self) -> Option<&FieldKey> {
         match &self.key {
@@ -72,6 +152,20 @@ impl ResolvedPathItem {
         &self.value
     }
 
+    /// The fully resolved path, always rendered with `/` separators.
+    ///
+    /// This is useful for logs that are shared between platforms, since the path will render the
+    /// same way on Windows as it does on Linux or macOS.
+    pub fn to_posix_string(&self) -> String {
+        let value = self.value.to_string_lossy();
+
+        if std::path::MAIN_SEPARATOR == '\\' {
+            value.replace('\\', "/")
+        } else {
+            value.into_owned()
+        }
+    }
+
     /// The permission for the path.
     ///
     /// There should be no inherited permissions at this point unless no permissions have been
@@ -104,6 +198,13 @@ impl ResolvedPathItem {
         self.deferred
     }
 
+    /// The creation order of the path relative to its siblings.
+    ///
+    /// Lower orders are created first.
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+
     /// Metadata for the workspace resolver.
     ///
     /// This could contain anything such as the specific user  that owns the path, or the source
@@ -113,10 +214,43 @@ impl ResolvedPathItem {
     }
 }
 
+/// A count of the paths that `create_workspace` would create.
+///
+/// This is built by [Config::workspace_summary](crate::Config::workspace_summary).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorkspaceSummary {
+    /// The number of directories.
+    pub dirs: usize,
+    /// The number of files.
+    pub files: usize,
+    /// The number of file templates.
+    pub templates: usize,
+}
+
+/// A node in the resolved workspace tree.
+///
+/// This is built by [get_workspace_tree](crate::get_workspace_tree), and is useful for rendering
+/// the workspace in a UI without having to rebuild the tree from the path prefixes.
+#[derive(Debug, Clone)]
+pub struct WorkspaceNode {
+    pub(crate) item: ResolvedPathItem,
+    pub(crate) children: Vec<WorkspaceNode>,
+}
+
+impl WorkspaceNode {
+    /// The resolved path item for this node.
+    pub fn item(&self) -> &ResolvedPathItem {
+        &self.item
+    }
+
+    /// The child nodes, sorted by their path.
+    pub fn children(&self) -> &[WorkspaceNode] {
+        &self.children
+    }
+}
+
 /// The permission for a path.
-#[derive(
-    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
-)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Permission {
     /// This should inherit from the parent path.
     #[default]
@@ -131,12 +265,15 @@ pub enum Permission {
     /// The workspace resolver's IO function is responsible for deciding what read and write means
     /// for a path.
     ReadWrite,
+    /// This path should have a site specific permission, such as an ACL group.
+    ///
+    /// The workspace resolver's IO function is responsible for deciding what the custom permission
+    /// means for a path.
+    Custom(String),
 }
 
 /// The owner of the path.
-#[derive(
-    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
-)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Owner {
     /// This should inherit from the parent path.
     #[default]
@@ -155,6 +292,10 @@ pub enum Owner {
     /// This is useful for workspaces where users will do their work in and should easily be able
     /// to read/write to.
     User,
+    /// This path should be owned by a site specific owner, such as an "editorial" group.
+    ///
+    /// The workspace resolver's IO function is responsible for deciding who the custom owner is.
+    Custom(String),
 }
 
 /// The type of the path.
@@ -172,3 +313,104 @@ pub enum PathType {
     /// The workspace resolver's IO function is responsible for how to resolve the template.
     FileTemplate,
 }
+
+impl std::str::FromStr for PathType {
+    type Err = crate::Error;
+
+    /// Parse the path type from the names used by text based configs, such as `"directory"`,
+    /// `"file"`, or `"file_template"`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "directory" => Ok(Self::Directory),
+            "file" => Ok(Self::File),
+            "file_template" => Ok(Self::FileTemplate),
+            _ => Err(crate::Error::new(format!(
+                "Invalid path type {value:?}, expected one of \"directory\", \"file\", or \"file_template\"."
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved_path_item(value: &str) -> ResolvedPathItem {
+        ResolvedPathItem::new(
+            None,
+            value,
+            Permission::default(),
+            Owner::default(),
+            PathType::default(),
+            false,
+            0,
+            std::collections::HashMap::new(),
+        )
+    }
+
+    #[test]
+    fn test_resolved_path_item_new_success() {
+        let item = ResolvedPathItem::new(
+            Some("key".try_into().unwrap()),
+            "/path/to/value",
+            Permission::ReadOnly,
+            Owner::Project,
+            PathType::File,
+            true,
+            2,
+            [("test".to_string(), crate::MetadataValue::Integer(123))]
+                .into_iter()
+                .collect(),
+        );
+
+        assert_eq!(item.key().map(|key| key.as_str()), Some("key"));
+        assert_eq!(item.value(), std::path::Path::new("/path/to/value"));
+        assert_eq!(item.permission(), &Permission::ReadOnly);
+        assert_eq!(item.owner(), &Owner::Project);
+        assert_eq!(item.path_type(), &PathType::File);
+        assert!(item.deferred());
+        assert_eq!(item.order(), 2);
+        assert_eq!(
+            item.metadata().get("test"),
+            Some(&crate::MetadataValue::Integer(123))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("directory", PathType::Directory)]
+    #[case("file", PathType::File)]
+    #[case("file_template", PathType::FileTemplate)]
+    fn test_path_type_from_str_success(#[case] input: &str, #[case] expected: PathType) {
+        assert_eq!(input.parse::<PathType>().unwrap(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case("symlink")]
+    #[case("Directory")]
+    #[case("")]
+    fn test_path_type_from_str_failure(#[case] input: &str) {
+        let err = input.parse::<PathType>().unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Invalid path type {input:?}, expected one of \"directory\", \"file\", or \"file_template\"."
+            )
+        );
+    }
+
+    #[test]
+    fn test_resolved_path_item_to_posix_string_success() {
+        let item = resolved_path_item("/path/to/value");
+
+        assert_eq!(item.to_posix_string(), "/path/to/value");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_resolved_path_item_to_posix_string_windows_success() {
+        let item = resolved_path_item(r"C:\path\to\value");
+
+        assert_eq!(item.to_posix_string(), "C:/path/to/value");
+    }
+}
@@ -1,24 +1,246 @@
 use serde::Deserialize;
 
 /// The resolver for the placeholders.
+///
+/// The enum is non-exhaustive, since the variants depend on the enabled features, such as the date
+/// variant with the `date` feature.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub enum Resolver {
     /// This is an undefined resolver and should be assumed to be a string.
     Default,
     /// This is a string resolver and may also have a shape.
-    #[serde(
-        serialize_with = "serialize_regex",
-        deserialize_with = "deserialize_regex"
-    )]
     String {
         /// The shape of a valid string.
+        #[serde(
+            serialize_with = "serialize_regex",
+            deserialize_with = "deserialize_regex"
+        )]
         pattern: Option<std::sync::Arc<regex::Regex>>,
+        /// Whether the string is percent-encoded in the path, such as `%20` for a space. The value
+        /// is encoded when drawing the path, and decoded when the value is extracted from a path.
+        #[serde(default)]
+        percent_encoded: bool,
     },
     /// This is an integer resolver.
     Integer {
         /// The zero padding to use for the integer, and the minimum number of numerical characters
-        /// to search for.
+        /// to search for. A padding of 0 does not pad the integer, and any number of digits are
+        /// matched, including leading zeros.
         padding: u8,
+        /// The separator to insert between every three digits, such as `1_000`. When the integers
+        /// are extracted from a path, then the separators are removed.
+        #[serde(default)]
+        group_separator: Option<char>,
+        /// The smallest valid integer. If this is not set, then the integer has no minimum.
+        #[serde(default)]
+        min: Option<u16>,
+        /// The largest valid integer. If this is not set, then the integer has no maximum.
+        #[serde(default)]
+        max: Option<u16>,
+    },
+    /// This is a float resolver.
+    Float {
+        /// The number of digits after the decimal point. The value is rounded to the precision
+        /// when drawing the path.
+        precision: u8,
+    },
+    /// This is a string that must be one of a fixed set of options, such as a department.
+    Choice {
+        /// The allowed values.
+        options: Vec<String>,
+    },
+    /// This is a date with a strftime-style format, such as `%Y_%m_%d`.
+    #[cfg(feature = "date")]
+    Date {
+        /// The format of the date. Only `%Y`, `%y`, `%m`, `%d`, `%j`, and `%%` are supported.
+        format: String,
+    },
+    /// This is a string of digits, such as a very large numeric ID.
+    ///
+    /// The value only matches digits like an integer, but it is kept as a string, so IDs that do
+    /// not fit into a path integer survive the round trip.
+    NumericString,
+}
+
+impl PartialEq for Resolver {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Default, Self::Default) => true,
+            (
+                Self::String {
+                    pattern,
+                    percent_encoded,
+                },
+                Self::String {
+                    pattern: other_pattern,
+                    percent_encoded: other_percent_encoded,
+                },
+            ) => {
+                pattern.as_ref().map(|pattern| pattern.as_str())
+                    == other_pattern.as_ref().map(|pattern| pattern.as_str())
+                    && percent_encoded == other_percent_encoded
+            }
+            (
+                Self::Integer {
+                    padding,
+                    group_separator,
+                    min,
+                    max,
+                },
+                Self::Integer {
+                    padding: other_padding,
+                    group_separator: other_group_separator,
+                    min: other_min,
+                    max: other_max,
+                },
+            ) => {
+                padding == other_padding
+                    && group_separator == other_group_separator
+                    && min == other_min
+                    && max == other_max
+            }
+            (
+                Self::Float { precision },
+                Self::Float {
+                    precision: other_precision,
+                },
+            ) => precision == other_precision,
+            (
+                Self::Choice { options },
+                Self::Choice {
+                    options: other_options,
+                },
+            ) => options == other_options,
+            #[cfg(feature = "date")]
+            (
+                Self::Date { format },
+                Self::Date {
+                    format: other_format,
+                },
+            ) => format == other_format,
+            (Self::NumericString, Self::NumericString) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Resolver {}
+
+impl std::hash::Hash for Resolver {
+    /// Hash the resolver by its kind and the source of its pattern, the same as [PartialEq].
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Self::Default | Self::NumericString => (),
+            Self::String {
+                pattern,
+                percent_encoded,
+            } => {
+                pattern.as_ref().map(|pattern| pattern.as_str()).hash(state);
+                percent_encoded.hash(state);
+            }
+            Self::Integer {
+                padding,
+                group_separator,
+                min,
+                max,
+            } => {
+                padding.hash(state);
+                group_separator.hash(state);
+                min.hash(state);
+                max.hash(state);
+            }
+            Self::Float { precision } => precision.hash(state),
+            Self::Choice { options } => options.hash(state),
+            #[cfg(feature = "date")]
+            Self::Date { format } => format.hash(state),
+        }
+    }
+}
+
+/// The specification of a resolver to register with
+/// [ConfigBuilder::add_resolvers](crate::ConfigBuilder::add_resolvers).
+///
+/// This is useful for mapping a config from another format straight into the builder.
+///
+/// The enum is non-exhaustive, since the variants depend on the enabled features, such as the date
+/// variant with the `date` feature.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub enum ResolverSpec {
+    /// A string resolver. See [ConfigBuilder::add_string_resolver](crate::ConfigBuilder::add_string_resolver).
+    String {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
+        /// The shape of a valid string.
+        pattern: Option<String>,
+    },
+    /// A string resolver for percent-encoded values. See
+    /// [ConfigBuilder::add_percent_encoded_string_resolver](crate::ConfigBuilder::add_percent_encoded_string_resolver).
+    PercentEncodedString {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
+        /// The shape of a valid encoded string.
+        pattern: Option<String>,
+    },
+    /// An integer resolver. See [ConfigBuilder::add_integer_resolver](crate::ConfigBuilder::add_integer_resolver).
+    Integer {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
+        /// The zero padding to use for the integer.
+        padding: u8,
+    },
+    /// An integer resolver that groups the digits. See
+    /// [ConfigBuilder::add_grouped_integer_resolver](crate::ConfigBuilder::add_grouped_integer_resolver).
+    GroupedInteger {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
+        /// The zero padding to use for the integer.
+        padding: u8,
+        /// The separator to insert between every three digits.
+        group_separator: char,
+    },
+    /// An integer resolver with a valid range. See
+    /// [ConfigBuilder::add_integer_resolver_with_bounds](crate::ConfigBuilder::add_integer_resolver_with_bounds).
+    BoundedInteger {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
+        /// The zero padding to use for the integer.
+        padding: u8,
+        /// The smallest valid integer.
+        min: u16,
+        /// The largest valid integer.
+        max: u16,
+    },
+    /// A float resolver. See [ConfigBuilder::add_float_resolver](crate::ConfigBuilder::add_float_resolver).
+    Float {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
+        /// The number of digits after the decimal point.
+        precision: u8,
+    },
+    /// A choice resolver. See [ConfigBuilder::add_choice_resolver](crate::ConfigBuilder::add_choice_resolver).
+    Choice {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
+        /// The allowed values.
+        options: Vec<String>,
+    },
+    /// A date resolver. See `ConfigBuilder::add_date_resolver`.
+    #[cfg(feature = "date")]
+    Date {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
+        /// The strftime-style format of the date.
+        format: String,
+    },
+    /// A numeric string resolver. See
+    /// [ConfigBuilder::add_numeric_string_resolver](crate::ConfigBuilder::add_numeric_string_resolver).
+    NumericString {
+        /// The key of the placeholder.
+        key: crate::FieldKey,
     },
 }
 
@@ -26,20 +248,233 @@ impl Resolver {
     pub(crate) fn pattern(&self) -> std::borrow::Cow<'_, str> {
         match self {
             Self::Default => ".+?".into(),
-            Self::String { pattern } => match pattern {
-                Some(pattern) => pattern.to_string().into(),
+            // User patterns are wrapped in a non-capturing group so that an alternation such as
+            // `a|b` cannot leak out into the rest of the component's pattern.
+            Self::String { pattern, .. } => match pattern {
+                Some(pattern) => format!("(?:{pattern})").into(),
                 None => ".+?".into(),
             },
-            Self::Integer { padding } => format!("\\d{{{},}}?", padding.max(&1)).into(),
+            Self::Integer {
+                group_separator: Some(group_separator),
+                ..
+            } => format!(
+                "\\d{{1,3}}(?:{}\\d{{3}})*?",
+                regex::escape(&group_separator.to_string())
+            )
+            .into(),
+            // Without a minimum width, there are no extra zeros to check for.
+            Self::Integer { padding: 0, .. } => "\\d+".into(),
+            // A value wider than the padding cannot start with a zero, since it would be drawn
+            // without the extra zeros. For example, `0012` is not a valid padding of 3.
+            Self::Integer { padding, .. } => {
+                format!("(?:\\d{{{padding}}}|[1-9]\\d{{{padding},}}?)").into()
+            }
+            Self::Float { precision: 0 } => "-?\\d+".into(),
+            Self::Float { precision } => format!("-?\\d+\\.\\d{{{precision}}}").into(),
+            Self::Choice { options } => format!(
+                "(?:{})",
+                options
+                    .iter()
+                    .map(|option| regex::escape(option))
+                    .collect::<Vec<_>>()
+                    .join("|")
+            )
+            .into(),
+            // The format is checked when the resolver is added, so an unsupported format can only
+            // come from a deserialized resolver. It never matches instead of matching anything.
+            #[cfg(feature = "date")]
+            Self::Date { format } => match date_pattern(format) {
+                Ok(pattern) => pattern.into(),
+                Err(_) => "[^\\s\\S]".into(),
+            },
+            Self::NumericString => "\\d+?".into(),
+        }
+    }
+
+    /// Whether the value matches the full pattern of the resolver.
+    pub(crate) fn is_match(&self, value: &str) -> Result<bool, crate::Error> {
+        let pattern = crate::cache::regex(&format!("^(?:{})$", self.pattern()))?;
+
+        Ok(pattern.is_match(value))
+    }
+
+    /// The smallest and largest valid integers. Resolvers that are not bounded integer resolvers
+    /// accept every integer.
+    pub(crate) fn integer_bounds(&self) -> (u16, u16) {
+        match self {
+            Self::Integer { min, max, .. } => (min.unwrap_or(u16::MIN), max.unwrap_or(u16::MAX)),
+            _ => (u16::MIN, u16::MAX),
+        }
+    }
+
+    /// Whether the value is within the resolver's range. Only bounded integer resolvers have a
+    /// range, so every other value is in range.
+    pub(crate) fn is_in_range(&self, value: &crate::PathValue) -> bool {
+        match value {
+            crate::PathValue::Integer(value) => {
+                let (min, max) = self.integer_bounds();
+
+                (min..=max).contains(value)
+            }
+            _ => true,
         }
     }
 
     pub(crate) fn to_path_value(&self, value: &str) -> Result<crate::PathValue, crate::Error> {
+        let value = self.parse_value(value)?;
+
+        if let crate::PathValue::Integer(integer) = value
+            && !self.is_in_range(&value)
+        {
+            let (min, max) = self.integer_bounds();
+
+            return Err(crate::Error::new(format!(
+                "Integer {integer} is out of range. Expected an integer from {min} to {max}."
+            )));
+        }
+
+        Ok(value)
+    }
+
+    /// Parse a value as it appears in a path, without checking that it is within the resolver's
+    /// range.
+    pub(crate) fn parse_value(&self, value: &str) -> Result<crate::PathValue, crate::Error> {
         match self {
             Self::Default => Ok(crate::PathValue::String(value.into())),
+            Self::String {
+                percent_encoded: true,
+                ..
+            } => Ok(crate::PathValue::String(percent_decode(value)?)),
             Self::String { .. } => Ok(crate::PathValue::String(value.into())),
+            Self::Integer {
+                group_separator: Some(group_separator),
+                ..
+            } => Ok(crate::PathValue::Integer(
+                value.replace(*group_separator, "").parse()?,
+            )),
             Self::Integer { .. } => Ok(crate::PathValue::Integer(value.parse()?)),
+            Self::Float { .. } => Ok(crate::PathValue::Float(value.parse()?)),
+            Self::Choice { .. } => Ok(crate::PathValue::String(value.into())),
+            #[cfg(feature = "date")]
+            Self::Date { format } => Ok(crate::PathValue::Date(parse_date(value, format)?)),
+            Self::NumericString => Ok(crate::PathValue::String(value.into())),
+        }
+    }
+}
+
+/// Convert a strftime-style date format into a regex pattern.
+#[cfg(feature = "date")]
+pub(crate) fn date_pattern(format: &str) -> Result<String, crate::Error> {
+    let mut pattern = String::with_capacity(format.len());
+    let mut characters = format.chars();
+
+    while let Some(character) = characters.next() {
+        if character != '%' {
+            pattern.push_str(&regex::escape(&character.to_string()));
+            continue;
+        }
+
+        match characters.next() {
+            Some('Y') => pattern.push_str("\\d{4}"),
+            Some('y' | 'm' | 'd') => pattern.push_str("\\d{2}"),
+            Some('j') => pattern.push_str("\\d{3}"),
+            Some('%') => pattern.push('%'),
+            Some(specifier) => {
+                return Err(crate::Error::new(format!(
+                    "The date format {format:?} has an unsupported specifier \"%{specifier}\"."
+                )));
+            }
+            None => {
+                return Err(crate::Error::new(format!(
+                    "The date format {format:?} ends with an incomplete specifier."
+                )));
+            }
+        }
+    }
+
+    Ok(pattern)
+}
+
+/// Parse a date with a strftime-style format.
+#[cfg(feature = "date")]
+pub(crate) fn parse_date(value: &str, format: &str) -> Result<chrono::NaiveDate, crate::Error> {
+    chrono::NaiveDate::parse_from_str(value, format).map_err(|_| {
+        crate::Error::new(format!(
+            "The value {value:?} is not a date in the format {format:?}."
+        ))
+    })
+}
+
+/// Encode every byte except for the unreserved characters of RFC 3986 as `%XX`.
+pub(crate) fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+/// Decode the `%XX` sequences of a percent-encoded string.
+fn percent_decode(value: &str) -> Result<String, crate::Error> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] != b'%' {
+            decoded.push(bytes[index]);
+            index += 1;
+            continue;
         }
+
+        // Parsing the digits alone would also accept a sign, such as `%+1`.
+        let byte = bytes
+            .get(index + 1..index + 3)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match byte {
+            Some(byte) => decoded.push(byte),
+            None => {
+                return Err(crate::Error::new(format!(
+                    "Invalid percent-encoding in {value:?}"
+                )));
+            }
+        }
+
+        index += 3;
+    }
+
+    match String::from_utf8(decoded) {
+        Ok(decoded) => Ok(decoded),
+        Err(_) => Err(crate::Error::new(format!(
+            "Invalid percent-encoding in {value:?}"
+        ))),
+    }
+}
+
+/// The largest compiled size of a string resolver's pattern, in bytes.
+///
+/// The regex crate always matches in linear time, but a pattern with a huge repetition, such as
+/// `a{1000000}`, can still use a lot of memory when it is compiled into every path pattern.
+pub(crate) const PATTERN_SIZE_LIMIT: usize = 1 << 20;
+
+/// Compile a string resolver's pattern, rejecting patterns that are larger than
+/// [PATTERN_SIZE_LIMIT] when compiled.
+pub(crate) fn compile_pattern(pattern: &str) -> Result<std::sync::Arc<regex::Regex>, crate::Error> {
+    match crate::cache::regex_with_size_limit(pattern, PATTERN_SIZE_LIMIT)? {
+        Ok(regex) => Ok(regex),
+        Err(regex::Error::CompiledTooBig(limit)) => Err(crate::Error::new(format!(
+            "The pattern {pattern:?} is too large. The compiled pattern must be smaller than {limit} bytes."
+        ))),
+        Err(err) => Err(crate::Error::new(format!("Regex compile error: {err}"))),
     }
 }
 
@@ -57,7 +492,7 @@ fn deserialize_regex<'de, D: serde::Deserializer<'de>>(
     deserializer: D,
 ) -> Result<Option<std::sync::Arc<regex::Regex>>, D::Error> {
     let regex = match Option::<String>::deserialize(deserializer)? {
-        Some(regex) => Some(crate::cache::regex(&regex).map_err(serde::de::Error::custom)?),
+        Some(regex) => Some(compile_pattern(&regex).map_err(serde::de::Error::custom)?),
         None => None,
     };
 
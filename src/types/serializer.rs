@@ -0,0 +1,386 @@
+use crate::types::TemplateObject;
+use crate::{FieldKey, TemplateValue};
+
+/// Serialize a value into a [TemplateValue] tree.
+///
+/// Structs and maps become objects, sequences and tuples become arrays, and enum variants with
+/// data become an object with the variant name as the only key. Map keys and struct field names
+/// must be valid field keys.
+pub(crate) struct TemplateValueSerializer;
+
+fn to_template_value<T: serde::Serialize + ?Sized>(
+    value: &T,
+) -> Result<TemplateValue, crate::Error> {
+    value.serialize(TemplateValueSerializer)
+}
+
+fn to_field_key(key: &str) -> Result<FieldKey, crate::Error> {
+    FieldKey::new(key).map_err(|_| crate::Error::new(format!("Invalid field key: {key:?}")))
+}
+
+fn variant_object(variant: &str, value: TemplateValue) -> Result<TemplateValue, crate::Error> {
+    let mut object = TemplateObject::new();
+    object.insert(to_field_key(variant)?, value);
+
+    Ok(TemplateValue::Object(object))
+}
+
+impl serde::Serializer for TemplateValueSerializer {
+    type Ok = TemplateValue;
+    type Error = crate::Error;
+    type SerializeSeq = SerializeArray;
+    type SerializeTuple = SerializeArray;
+    type SerializeTupleStruct = SerializeArray;
+    type SerializeTupleVariant = SerializeArray;
+    type SerializeMap = SerializeObject;
+    type SerializeStruct = SerializeObject;
+    type SerializeStructVariant = SerializeObject;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        v.try_into()
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        Ok(v.to_string().into())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        Ok(v.into())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Ok(TemplateValue::Array(
+            v.iter().map(|byte| (*byte).into()).collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(TemplateValue::None)
+    }
+
+    fn serialize_some<T: serde::Serialize + ?Sized>(
+        self,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        to_template_value(value)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(TemplateValue::None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(TemplateValue::None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant.into())
+    }
+
+    fn serialize_newtype_struct<T: serde::Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        to_template_value(value)
+    }
+
+    fn serialize_newtype_variant<T: serde::Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        variant_object(variant, to_template_value(value)?)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(SerializeArray {
+            variant: None,
+            array: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(SerializeArray {
+            variant: Some(variant),
+            array: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(SerializeObject {
+            variant: None,
+            object: TemplateObject::with_capacity(len.unwrap_or_default()),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(SerializeObject {
+            variant: Some(variant),
+            object: TemplateObject::with_capacity(len),
+            next_key: None,
+        })
+    }
+}
+
+pub(crate) struct SerializeArray {
+    variant: Option<&'static str>,
+    array: Vec<TemplateValue>,
+}
+
+impl SerializeArray {
+    fn push<T: serde::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), crate::Error> {
+        self.array.push(to_template_value(value)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<TemplateValue, crate::Error> {
+        let value = TemplateValue::Array(self.array);
+
+        match self.variant {
+            Some(variant) => variant_object(variant, value),
+            None => Ok(value),
+        }
+    }
+}
+
+impl serde::ser::SerializeSeq for SerializeArray {
+    type Ok = TemplateValue;
+    type Error = crate::Error;
+
+    fn serialize_element<T: serde::Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl serde::ser::SerializeTuple for SerializeArray {
+    type Ok = TemplateValue;
+    type Error = crate::Error;
+
+    fn serialize_element<T: serde::Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl serde::ser::SerializeTupleStruct for SerializeArray {
+    type Ok = TemplateValue;
+    type Error = crate::Error;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl serde::ser::SerializeTupleVariant for SerializeArray {
+    type Ok = TemplateValue;
+    type Error = crate::Error;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+pub(crate) struct SerializeObject {
+    variant: Option<&'static str>,
+    object: TemplateObject,
+    next_key: Option<FieldKey>,
+}
+
+impl SerializeObject {
+    fn insert<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: &str,
+        value: &T,
+    ) -> Result<(), crate::Error> {
+        self.object
+            .insert(to_field_key(key)?, to_template_value(value)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<TemplateValue, crate::Error> {
+        let value = TemplateValue::Object(self.object);
+
+        match self.variant {
+            Some(variant) => variant_object(variant, value),
+            None => Ok(value),
+        }
+    }
+}
+
+impl serde::ser::SerializeMap for SerializeObject {
+    type Ok = TemplateValue;
+    type Error = crate::Error;
+
+    fn serialize_key<T: serde::Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        let key = match to_template_value(key)? {
+            TemplateValue::String(key) => to_field_key(&key)?,
+            key => {
+                return Err(crate::Error::new(format!(
+                    "Object keys must be strings, found {key:?}"
+                )));
+            }
+        };
+        self.next_key = Some(key);
+
+        Ok(())
+    }
+
+    fn serialize_value<T: serde::Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        let key = match self.next_key.take() {
+            Some(key) => key,
+            None => return Err(crate::Error::new("Object value serialized before its key")),
+        };
+        self.object.insert(key, to_template_value(value)?);
+
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl serde::ser::SerializeStruct for SerializeObject {
+    type Ok = TemplateValue;
+    type Error = crate::Error;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
+
+impl serde::ser::SerializeStructVariant for SerializeObject {
+    type Ok = TemplateValue;
+    type Error = crate::Error;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.finish()
+    }
+}
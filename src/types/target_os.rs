@@ -0,0 +1,115 @@
+/// The operating system that the resolved paths must be valid on.
+///
+/// See [ConfigBuilder::set_target_os](crate::ConfigBuilder::set_target_os).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum TargetOs {
+    /// A name must not contain a null character.
+    Posix,
+    /// A name must not contain the characters `<>:"|?*` or control characters, must not end with
+    /// a space or `.`, and must not be a reserved name such as `CON`, `PRN`, or `COM1`.
+    Windows,
+}
+
+const WINDOWS_RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+impl TargetOs {
+    /// Validate a single path component, such as a file or directory name.
+    pub(crate) fn validate_name(&self, name: &str) -> Result<(), crate::Error> {
+        match self {
+            Self::Posix => {
+                if name.contains('\0') {
+                    return Err(crate::Error::new(format!(
+                        "The name {name:?} is not valid on POSIX: it contains a null character."
+                    )));
+                }
+            }
+            Self::Windows => {
+                if let Some(c) = name.chars().find(|c| {
+                    matches!(c, '<' | '>' | ':' | '"' | '|' | '?' | '*') || c.is_control()
+                }) {
+                    return Err(crate::Error::new(format!(
+                        "The name {name:?} is not valid on Windows: it contains {c:?}."
+                    )));
+                }
+
+                if name.ends_with([' ', '.']) && name != "." && name != ".." {
+                    return Err(crate::Error::new(format!(
+                        "The name {name:?} is not valid on Windows: it ends with a space or \".\"."
+                    )));
+                }
+
+                // The reserved names are also reserved with an extension, such as `CON.txt`.
+                let stem = match name.split_once('.') {
+                    Some((stem, _)) => stem,
+                    None => name,
+                };
+
+                if WINDOWS_RESERVED_NAMES
+                    .iter()
+                    .any(|reserved| reserved.eq_ignore_ascii_case(stem.trim_end()))
+                {
+                    return Err(crate::Error::new(format!(
+                        "The name {name:?} is not valid on Windows: it is a reserved name."
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rstest::rstest]
+    #[case(TargetOs::Windows, "shot_v001.exr")]
+    #[case(TargetOs::Windows, "CONSOLE")]
+    #[case(TargetOs::Windows, "..")]
+    #[case(TargetOs::Posix, "a:b")]
+    #[case(TargetOs::Posix, "CON")]
+    fn test_target_os_validate_name_success(#[case] target_os: TargetOs, #[case] name: &str) {
+        assert!(target_os.validate_name(name).is_ok());
+    }
+
+    #[rstest::rstest]
+    #[case(
+        TargetOs::Windows,
+        "a:b",
+        "The name \"a:b\" is not valid on Windows: it contains ':'."
+    )]
+    #[case(
+        TargetOs::Windows,
+        "CON",
+        "The name \"CON\" is not valid on Windows: it is a reserved name."
+    )]
+    #[case(
+        TargetOs::Windows,
+        "lpt1.txt",
+        "The name \"lpt1.txt\" is not valid on Windows: it is a reserved name."
+    )]
+    #[case(
+        TargetOs::Windows,
+        "shot.",
+        "The name \"shot.\" is not valid on Windows: it ends with a space or \".\"."
+    )]
+    #[case(
+        TargetOs::Posix,
+        "a\0b",
+        "The name \"a\\0b\" is not valid on POSIX: it contains a null character."
+    )]
+    fn test_target_os_validate_name_failure(
+        #[case] target_os: TargetOs,
+        #[case] name: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            target_os.validate_name(name).unwrap_err().to_string(),
+            expected
+        );
+    }
+}
@@ -1,9 +1,177 @@
+use crate::types::resolver::percent_encode;
+use crate::types::value::{draw_float, draw_integer};
 use crate::types::{FieldKey, PathAttributes, PathValue, Resolver, Resolvers};
 
+/// The text that replaces the value of a secret field.
+pub(crate) const REDACTED: &str = "***";
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) enum Token {
     Literal(String),
-    Variable(FieldKey),
+    Variable {
+        key: FieldKey,
+        default: Option<String>,
+    },
+    /// An optional `.` and field value at the end of a path component, such as `{?.ext}`.
+    OptionalExtension {
+        key: FieldKey,
+    },
+    /// The remaining path components, `{**}`. This is only used to find paths.
+    CatchAll,
+}
+
+/// Draw the value of a field, or its default if the field is missing.
+///
+/// Integers are drawn with the zero padding of their resolver, so a padding of 0 does not add any
+/// zeros.
+fn draw_variable(
+    buf: &mut impl std::fmt::Write,
+    variable: &FieldKey,
+    default: Option<&str>,
+    fields: &PathAttributes,
+    resolvers: &Resolvers,
+) -> Result<(), crate::Error> {
+    let resolver = match resolvers.get(variable) {
+        Some(resolver) => resolver,
+        None => &Resolver::Default,
+    };
+    let default_value;
+    let value = match (fields.get(variable), default) {
+        (Some(value), _) => value,
+        (None, Some(default)) => {
+            default_value = resolver.to_path_value(default)?;
+            &default_value
+        }
+        (None, None) => {
+            return Err(crate::Error::new(format!(
+                "Could not find {:?} in the fields.",
+                variable.as_str()
+            )));
+        }
+    };
+    match value {
+        PathValue::Integer(v) => {
+            let (padding, group_separator) = match resolver {
+                Resolver::Default | Resolver::NumericString => (0, None),
+                Resolver::Integer {
+                    padding,
+                    group_separator,
+                    ..
+                } => (*padding, *group_separator),
+                _ => {
+                    return Err(crate::Error::new(format!(
+                        "Resolver type {resolver:?} is invalid for value {value:?}."
+                    )));
+                }
+            };
+            let (min, max) = resolver.integer_bounds();
+
+            if *v < min || *v > max {
+                return Err(crate::Error::new(format!(
+                    "Value {v} for {:?} is out of range. Expected an integer from {min} to {max}.",
+                    variable.as_str()
+                )));
+            }
+
+            match buf.write_str(&draw_integer(*v, padding, group_separator)) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(crate::Error::new(format!(
+                    "Error while formatting: {error}"
+                ))),
+            }
+        }
+        PathValue::Float(v) => {
+            let drawn = match resolver {
+                Resolver::Default => v.to_string(),
+                Resolver::Float { precision } => draw_float(*v, *precision),
+                _ => {
+                    return Err(crate::Error::new(format!(
+                        "Resolver type {resolver:?} is invalid for value {value:?}."
+                    )));
+                }
+            };
+
+            if !v.is_finite() {
+                return Err(crate::Error::new(format!(
+                    "The float {v} for {:?} cannot be drawn in a path.",
+                    variable.as_str()
+                )));
+            }
+
+            match buf.write_str(&drawn) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(crate::Error::new(format!(
+                    "Error while formatting: {error}"
+                ))),
+            }
+        }
+        #[cfg(feature = "date")]
+        PathValue::Date(v) => {
+            let drawn = match resolver {
+                Resolver::Default => v.to_string(),
+                Resolver::Date { format } => crate::types::value::draw_date(v, format)?,
+                _ => {
+                    return Err(crate::Error::new(format!(
+                        "Resolver type {resolver:?} is invalid for value {value:?}."
+                    )));
+                }
+            };
+
+            match buf.write_str(&drawn) {
+                Ok(_) => Ok(()),
+                Err(error) => Err(crate::Error::new(format!(
+                    "Error while formatting: {error}"
+                ))),
+            }
+        }
+        PathValue::String(v) => {
+            // A date may be passed as a string in the resolver's format.
+            #[cfg(feature = "date")]
+            if let Resolver::Date { format } = resolver {
+                let date = crate::types::resolver::parse_date(v, format)?;
+
+                return match buf.write_str(&crate::types::value::draw_date(&date, format)?) {
+                    Ok(_) => Ok(()),
+                    Err(error) => Err(crate::Error::new(format!(
+                        "Error while formatting: {error}"
+                    ))),
+                };
+            }
+
+            // Validate that the resolver type and the field type match
+            match resolver {
+                Resolver::Default | Resolver::String { .. } | Resolver::NumericString => (),
+                Resolver::Choice { options } => {
+                    if !options.contains(v) {
+                        return Err(crate::Error::new(format!(
+                            "Value {v:?} for {:?} is not one of the options {options:?}.",
+                            variable.as_str()
+                        )));
+                    }
+                }
+                _ => {
+                    return Err(crate::Error::new(format!(
+                        "Resolver type {resolver:?} is invalid for value {value:?}."
+                    )));
+                }
+            };
+
+            let result = match resolver {
+                Resolver::String {
+                    percent_encoded: true,
+                    ..
+                } => buf.write_str(&percent_encode(v)),
+                _ => buf.write_str(v),
+            };
+
+            match result {
+                Ok(_) => Ok(()),
+                Err(error) => Err(crate::Error::new(format!(
+                    "Error while formatting: {error}"
+                ))),
+            }
+        }
+    }
 }
 
 impl Token {
@@ -20,68 +188,41 @@ impl Token {
                     "Error while formatting token: {error}"
                 ))),
             },
-            Self::Variable(variable) => {
-                let value = match fields.get(variable) {
-                    Some(value) => value,
-                    None => {
-                        return Err(crate::Error::new(format!(
-                            "Could not find {:?} in the fields.",
-                            variable.as_str()
-                        )));
-                    }
-                };
-                let resolver = match resolvers.get(variable) {
-                    Some(resolver) => resolver,
-                    None => &Resolver::Default,
-                };
-                match value {
-                    PathValue::Integer(v) => {
-                        let padding = match resolver {
-                            Resolver::Default => 0,
-                            Resolver::Integer { padding } => *padding,
-                            _ => {
-                                return Err(crate::Error::new(format!(
-                                    "Resolver type {resolver:?} is invalid for value {value:?}."
-                                )));
-                            }
-                        };
-                        match write!(buf, "{:0width$}", v, width = padding as usize) {
-                            Ok(_) => Ok(()),
-                            Err(error) => Err(crate::Error::new(format!(
-                                "Error while formatting: {error}"
-                            ))),
-                        }
-                    }
-                    PathValue::String(v) => {
-                        // Validate that the resolver type and the field type match
-                        match resolver {
-                            Resolver::Default | Resolver::String { .. } => (),
-                            _ => {
-                                return Err(crate::Error::new(format!(
-                                    "Resolver type {resolver:?} is invalid for value {value:?}."
-                                )));
-                            }
-                        };
-
-                        match buf.write_str(v) {
-                            Ok(_) => Ok(()),
-                            Err(error) => Err(crate::Error::new(format!(
-                                "Error while formatting: {error}"
-                            ))),
-                        }
-                    }
+            Self::Variable { key, default } => {
+                draw_variable(buf, key, default.as_deref(), fields, resolvers)
+            }
+            Self::OptionalExtension { key } => {
+                if fields.get(key).is_none() {
+                    return Ok(());
+                }
+
+                if let Err(error) = buf.write_char('.') {
+                    return Err(crate::Error::new(format!(
+                        "Error while formatting token: {error}"
+                    )));
                 }
+
+                draw_variable(buf, key, None, fields, resolvers)
             }
+            Self::CatchAll => Err(crate::Error::new(
+                "The catch-all {**} can only be used to find paths.",
+            )),
         }
     }
 
     fn is_resolved_by(&self, fields: &PathAttributes) -> bool {
         match self {
             Self::Literal(_) => true,
-            Self::Variable(variable) => fields.get(variable).is_some(),
+            Self::Variable {
+                key: variable,
+                default,
+            } => default.is_some() || fields.get(variable).is_some(),
+            Self::OptionalExtension { .. } => true,
+            Self::CatchAll => false,
         }
     }
 
+    #[cfg(feature = "std")]
     fn try_to_literal_token(
         &self,
         fields: &PathAttributes,
@@ -89,9 +230,10 @@ impl Token {
     ) -> Result<Self, crate::Error> {
         match self {
             Self::Literal(literal) => Ok(Self::Literal(literal.clone())),
-            Self::Variable(variable) => {
+            Self::CatchAll => Ok(Self::CatchAll),
+            Self::Variable { key: variable, .. } | Self::OptionalExtension { key: variable } => {
                 if fields.get(variable).is_none() {
-                    Ok(Self::Variable(variable.clone()))
+                    Ok(self.clone())
                 } else {
                     let mut buf = String::new();
                     self.draw(&mut buf, fields, resolvers)?;
@@ -101,6 +243,36 @@ impl Token {
         }
     }
 
+    #[cfg(feature = "std")]
+    fn draw_glob_pattern(&self, buf: &mut impl std::fmt::Write) -> Result<(), crate::Error> {
+        match self {
+            Token::Literal(literal) => {
+                let mut escape_buf = String::new();
+
+                for character in literal.chars() {
+                    if character == '/' || character == '\\' {
+                        buf.write_str(&glob::Pattern::escape(&escape_buf))?;
+                        escape_buf.clear();
+                        buf.write_char(std::path::MAIN_SEPARATOR)?;
+                    } else {
+                        escape_buf.push(character);
+                    }
+                }
+
+                buf.write_str(&glob::Pattern::escape(&escape_buf))?;
+            }
+            Token::Variable { .. } | Token::OptionalExtension { .. } => buf.write_char('*')?,
+            // `**` alone only matches directories, so the trailing `*` also matches the files.
+            Token::CatchAll => {
+                buf.write_str("**")?;
+                buf.write_char(std::path::MAIN_SEPARATOR)?;
+                buf.write_char('*')?;
+            }
+        };
+
+        Ok(())
+    }
+
     fn draw_regex_pattern(
         &self,
         buf: &mut impl std::fmt::Write,
@@ -124,7 +296,7 @@ impl Token {
 
                 Ok(())
             }
-            Self::Variable(variable) => {
+            Self::Variable { key: variable, .. } => {
                 let resolver = match resolvers.get(variable) {
                     Some(resolver) => resolver,
                     None => &Resolver::Default,
@@ -134,24 +306,28 @@ impl Token {
                 buf.write_char(')')?;
                 Ok(())
             }
-        }
-    }
+            Self::OptionalExtension { key } => {
+                let resolver = match resolvers.get(key) {
+                    Some(resolver) => resolver,
+                    None => &Resolver::Default,
+                };
+                buf.write_str(r"(?:\.(")?;
 
-    fn draw_glob_pattern(&self, buf: &mut impl std::fmt::Write) -> Result<(), crate::Error> {
-        match self {
-            Token::Literal(literal) => {
-                for character in literal.chars() {
-                    if character == '/' || character == '\\' {
-                        buf.write_char(std::path::MAIN_SEPARATOR)?;
-                    } else {
-                        buf.write_char(character)?;
-                    }
+                // The extension is everything after the last dot, so a name such as
+                // `plate.1001.exr` keeps its frame number.
+                match resolver {
+                    Resolver::Default => buf.write_str(r"[^.]+")?,
+                    _ => buf.write_str(&resolver.pattern())?,
                 }
-            }
-            Token::Variable(_) => buf.write_char('*')?,
-        };
 
-        Ok(())
+                buf.write_str("))?")?;
+                Ok(())
+            }
+            Self::CatchAll => {
+                buf.write_str(".+")?;
+                Ok(())
+            }
+        }
     }
 }
 
@@ -159,7 +335,13 @@ impl std::fmt::Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Literal(literal) => write!(f, "{}", literal),
-            Self::Variable(variable) => write!(f, "{{{}}}", variable),
+            Self::Variable { key, default: None } => write!(f, "{{{}}}", key),
+            Self::Variable {
+                key,
+                default: Some(default),
+            } => write!(f, "{{{}|{}}}", key, default),
+            Self::OptionalExtension { key } => write!(f, "{{?.{}}}", key),
+            Self::CatchAll => write!(f, "{{**}}"),
         }
     }
 }
@@ -190,6 +372,38 @@ impl Tokens {
         Ok(())
     }
 
+    /// Draw the tokens, but write `***` instead of the value of any secret field.
+    pub(crate) fn draw_redacted(
+        &self,
+        buf: &mut impl std::fmt::Write,
+        fields: &PathAttributes,
+        resolvers: &Resolvers,
+        secret_fields: &std::collections::HashSet<FieldKey>,
+    ) -> Result<(), crate::Error> {
+        for token in self.tokens.iter() {
+            match token {
+                Token::Variable { key, .. } if secret_fields.contains(key) => {
+                    if let Err(error) = buf.write_str(REDACTED) {
+                        return Err(crate::Error::new(format!(
+                            "Error while formatting token: {error}"
+                        )));
+                    }
+                }
+                Token::OptionalExtension { key }
+                    if secret_fields.contains(key) && fields.get(key).is_some() =>
+                {
+                    if let Err(error) = buf.write_char('.').and_then(|_| buf.write_str(REDACTED)) {
+                        return Err(crate::Error::new(format!(
+                            "Error while formatting token: {error}"
+                        )));
+                    }
+                }
+                _ => token.draw(buf, fields, resolvers)?,
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn is_resolved_by(&self, fields: &PathAttributes) -> bool {
         for token in self.tokens.iter() {
             if !token.is_resolved_by(fields) {
@@ -200,6 +414,7 @@ impl Tokens {
         true
     }
 
+    #[cfg(feature = "std")]
     pub(crate) fn try_to_literal_token(
         &self,
         fields: &PathAttributes,
@@ -214,30 +429,72 @@ impl Tokens {
         Ok(Self { tokens })
     }
 
-    pub(crate) fn draw_regex_pattern(
+    #[cfg(feature = "std")]
+    pub(crate) fn draw_glob_pattern(
         &self,
         buf: &mut impl std::fmt::Write,
-        resolvers: &Resolvers,
     ) -> Result<(), crate::Error> {
+        let mut previous_is_wildcard = false;
+
         for token in self.tokens.iter() {
-            token.draw_regex_pattern(buf, resolvers)?;
+            let is_wildcard = !matches!(token, Token::Literal(_));
+
+            // Adjacent placeholders share one wildcard, since `**` is only valid as a whole
+            // component.
+            if !(is_wildcard && previous_is_wildcard) {
+                token.draw_glob_pattern(buf)?;
+            }
+
+            previous_is_wildcard = is_wildcard;
         }
         Ok(())
     }
 
-    pub(crate) fn draw_glob_pattern(
+    pub(crate) fn draw_regex_pattern(
         &self,
         buf: &mut impl std::fmt::Write,
+        resolvers: &Resolvers,
     ) -> Result<(), crate::Error> {
         for token in self.tokens.iter() {
-            token.draw_glob_pattern(buf)?;
+            token.draw_regex_pattern(buf, resolvers)?;
+        }
+        Ok(())
+    }
+
+    /// Validate that the variable defaults are valid values for their resolvers.
+    pub(crate) fn validate_defaults(&self, resolvers: &Resolvers) -> Result<(), crate::Error> {
+        for token in self.tokens.iter() {
+            if let Token::Variable {
+                key,
+                default: Some(default),
+            } = token
+            {
+                let resolver = match resolvers.get(key) {
+                    Some(resolver) => resolver,
+                    None => &Resolver::Default,
+                };
+                if !resolver.is_match(default)? || resolver.to_path_value(default).is_err() {
+                    return Err(crate::Error::new(format!(
+                        "Default {default:?} is invalid for {:?}.",
+                        key.as_str()
+                    )));
+                }
+            }
         }
+
         Ok(())
     }
 
+    /// Whether the tokens are the catch-all `{**}` on its own.
+    pub(crate) fn is_catch_all(&self) -> bool {
+        matches!(self.tokens.as_slice(), [Token::CatchAll])
+    }
+
     pub(crate) fn has_variable_tokens(&self) -> bool {
         for token in self.tokens.iter() {
-            if let Token::Variable(_) = token {
+            if let Token::Variable { .. } | Token::OptionalExtension { .. } | Token::CatchAll =
+                token
+            {
                 return true;
             }
         }
@@ -245,6 +502,16 @@ impl Tokens {
         false
     }
 
+    /// The keys of the required placeholders. Optional extensions are skipped, since a path
+    /// without the extension is still complete.
+    #[cfg(feature = "std")]
+    pub(crate) fn variable_keys(&self) -> impl Iterator<Item = &FieldKey> {
+        self.tokens.iter().filter_map(|token| match token {
+            Token::Variable { key, .. } => Some(key),
+            Token::Literal(_) | Token::OptionalExtension { .. } | Token::CatchAll => None,
+        })
+    }
+
     fn recursive_to_tokens(text: &str, tokens: &mut Vec<Token>) -> Result<(), crate::Error> {
         let (literal, variable, after) = Self::parse(text)?;
 
@@ -253,7 +520,7 @@ impl Tokens {
         }
 
         if !variable.is_empty() {
-            tokens.push(Token::Variable(variable.try_into()?));
+            tokens.push(Self::parse_placeholder(variable)?);
         }
 
         if !after.is_empty() {
@@ -283,12 +550,63 @@ impl Tokens {
         };
         let (inside, after) = after.split_at(end_index + 1);
         let inside = &inside[1..inside.len() - 1].trim();
+        Self::parse_placeholder(inside)?;
+
+        Ok((before, inside, after))
+    }
+
+    /// Convert the inside of a placeholder into a token.
+    ///
+    /// A placeholder that starts with `?.`, such as `{?.ext}`, is an optional extension. It is
+    /// drawn as a `.` and the value when the field is supplied, and as nothing when it is not.
+    ///
+    /// The placeholder `{**}` is a catch-all for the remaining path components.
+    fn parse_placeholder(text: &str) -> Result<Token, crate::Error> {
+        if text == "**" {
+            return Ok(Token::CatchAll);
+        }
+
+        if let Some(key) = text.strip_prefix("?.") {
+            let key = key.trim();
 
-        if !FieldKey::validate(inside) {
+            if !FieldKey::validate(key) {
+                return Err(crate::Error::new("Parse Error: Invalid variable"));
+            }
+
+            return Ok(Token::OptionalExtension {
+                key: key.try_into()?,
+            });
+        }
+
+        let (key, default) = Self::parse_variable(text)?;
+
+        Ok(Token::Variable {
+            key: key.try_into()?,
+            default: default.map(|default| default.to_string()),
+        })
+    }
+
+    /// Split the inside of a variable into the key and the optional default.
+    ///
+    /// The default is separated from the key with a pipe, such as `{ext|exr}`, or an equals sign,
+    /// such as `{ext=exr}`. Both forms are the same token, and it is always displayed with a pipe.
+    fn parse_variable(text: &str) -> Result<(&str, Option<&str>), crate::Error> {
+        let (key, default) = match text.split_once(['|', '=']) {
+            Some((key, default)) => (key.trim(), Some(default.trim())),
+            None => (text, None),
+        };
+
+        if !FieldKey::validate(key) {
             return Err(crate::Error::new("Parse Error: Invalid variable"));
         }
 
-        Ok((before, inside, after))
+        match default {
+            Some("") => Err(crate::Error::new("Parse Error: Missing default")),
+            Some(default) if default.contains(['{', '|']) => {
+                Err(crate::Error::new("Parse Error: Invalid default"))
+            }
+            _ => Ok((key, default)),
+        }
     }
 }
 
@@ -397,7 +715,10 @@ mod tests {
     #[case("test_int_no_zpad", "1")]
     #[case("test_int_with_zpad", "001")]
     fn test_token_draw_variable_success(#[case] input: &str, #[case] expected: &str) {
-        let token = Token::Variable(input.try_into().unwrap());
+        let token = Token::Variable {
+            key: input.try_into().unwrap(),
+            default: None,
+        };
 
         let mut result = String::new();
         let mut fields = PathAttributes::new();
@@ -408,15 +729,28 @@ mod tests {
         let mut resolvers = Resolvers::new();
         resolvers.insert(
             "test_str".try_into().unwrap(),
-            Resolver::String { pattern: None },
+            Resolver::String {
+                pattern: None,
+                percent_encoded: false,
+            },
         );
         resolvers.insert(
             "test_int_no_zpad".try_into().unwrap(),
-            Resolver::Integer { padding: 0 },
+            Resolver::Integer {
+                padding: 0,
+                group_separator: None,
+                min: None,
+                max: None,
+            },
         );
         resolvers.insert(
             "test_int_with_zpad".try_into().unwrap(),
-            Resolver::Integer { padding: 3 },
+            Resolver::Integer {
+                padding: 3,
+                group_separator: None,
+                min: None,
+                max: None,
+            },
         );
 
         token.draw(&mut result, &fields, &resolvers).unwrap();
@@ -426,7 +760,10 @@ mod tests {
 
     #[test]
     fn test_token_draw_variable_failure_missing_field() {
-        let token = Token::Variable("test".try_into().unwrap());
+        let token = Token::Variable {
+            key: "test".try_into().unwrap(),
+            default: None,
+        };
         let mut writer = String::new();
         let err = token
             .draw(&mut writer, &PathAttributes::new(), &Resolvers::new())
@@ -437,7 +774,10 @@ mod tests {
 
     #[test]
     fn test_token_draw_variable_failure_int_resolver_mismatch() {
-        let token = Token::Variable("test".try_into().unwrap());
+        let token = Token::Variable {
+            key: "test".try_into().unwrap(),
+            default: None,
+        };
         let mut writer = String::new();
         let fields = {
             let mut fields = PathAttributes::new();
@@ -448,7 +788,10 @@ mod tests {
             let mut resolvers = Resolvers::new();
             resolvers.insert(
                 "test".try_into().unwrap(),
-                Resolver::String { pattern: None },
+                Resolver::String {
+                    pattern: None,
+                    percent_encoded: false,
+                },
             );
             resolvers
         };
@@ -456,13 +799,16 @@ mod tests {
 
         assert_eq!(
             err.to_string(),
-            "Resolver type String { pattern: None } is invalid for value Integer(1)."
+            "Resolver type String { pattern: None, percent_encoded: false } is invalid for value Integer(1)."
         );
     }
 
     #[test]
     fn test_token_draw_variable_failure_str_resolver_mismatch() {
-        let token = Token::Variable("test".try_into().unwrap());
+        let token = Token::Variable {
+            key: "test".try_into().unwrap(),
+            default: None,
+        };
         let mut writer = String::new();
         let fields = {
             let mut fields = PathAttributes::new();
@@ -471,14 +817,22 @@ mod tests {
         };
         let resolvers = {
             let mut resolvers = Resolvers::new();
-            resolvers.insert("test".try_into().unwrap(), Resolver::Integer { padding: 1 });
+            resolvers.insert(
+                "test".try_into().unwrap(),
+                Resolver::Integer {
+                    padding: 1,
+                    group_separator: None,
+                    min: None,
+                    max: None,
+                },
+            );
             resolvers
         };
         let err = token.draw(&mut writer, &fields, &resolvers).unwrap_err();
 
         assert_eq!(
             err.to_string(),
-            "Resolver type Integer { padding: 1 } is invalid for value String(\"test\")."
+            "Resolver type Integer { padding: 1, group_separator: None, min: None, max: None } is invalid for value String(\"test\")."
         );
     }
 
@@ -497,7 +851,10 @@ mod tests {
         let mut fields = PathAttributes::new();
         fields.insert("test_str".try_into().unwrap(), "test".into());
         fields.insert("test_int".try_into().unwrap(), 1u8.into());
-        let token = Token::Variable(input.try_into().unwrap());
+        let token = Token::Variable {
+            key: input.try_into().unwrap(),
+            default: None,
+        };
         let mut writer = TestWriter;
         let err = token
             .draw(&mut writer, &fields, &Resolvers::new())
@@ -520,6 +877,9 @@ mod tests {
     #[case("abc {def}", ("abc ", "def", ""))]
     #[case("{abc}def", ("", "abc", "def"))]
     #[case("{abc}{def}", ("", "abc", "{def}"))]
+    #[case("{ext|exr}", ("", "ext|exr", ""))]
+    #[case("{ ext | exr }", ("", "ext | exr", ""))]
+    #[case("{thing=unknown}", ("", "thing=unknown", ""))]
     fn test_tokens_parse_success(#[case] input: &str, #[case] expected: (&str, &str, &str)) {
         let result = Tokens::parse(input).unwrap();
         assert_eq!(result, expected);
@@ -540,6 +900,9 @@ mod tests {
     #[case("{abc.def.}", "Invalid variable")]
     #[case("{abc.def..}", "Invalid variable")]
     #[case("{{abc}}", "Invalid variable")]
+    #[case("{|exr}", "Invalid variable")]
+    #[case("{ext|}", "Missing default")]
+    #[case("{ext|exr|tif}", "Invalid default")]
     fn test_tokens_parse_failure(#[case] input: &str, #[case] expected: &str) {
         let result = Tokens::parse(input).unwrap_err();
 
@@ -549,14 +912,22 @@ mod tests {
     #[rstest::rstest]
     #[case("", &[])]
     #[case("abc", &[Token::Literal("abc".to_string())])]
-    #[case("{abc}", &[Token::Variable("abc".try_into().unwrap())])]
-    #[case("{abc123}", &[Token::Variable("abc123".try_into().unwrap())])]
-    #[case("{abc.def}", &[Token::Variable("abc.def".try_into().unwrap())])]
-    #[case("{ abc }", &[Token::Variable("abc".try_into().unwrap())])]
-    #[case("abc{def}", &[Token::Literal("abc".to_string()), Token::Variable("def".try_into().unwrap())])]
-    #[case("abc {def}", &[Token::Literal("abc ".to_string()), Token::Variable("def".try_into().unwrap())])]
-    #[case("{abc}def", &[Token::Variable("abc".try_into().unwrap()), Token::Literal("def".try_into().unwrap())])]
-    #[case("{abc}{def}", &[Token::Variable("abc".try_into().unwrap()), Token::Variable("def".try_into().unwrap())])]
+    #[case("{abc}", &[Token::Variable { key: "abc".try_into().unwrap(), default: None }])]
+    #[case("{abc123}", &[Token::Variable { key: "abc123".try_into().unwrap(), default: None }])]
+    #[case("{abc.def}", &[Token::Variable { key: "abc.def".try_into().unwrap(), default: None }])]
+    #[case("{ abc }", &[Token::Variable { key: "abc".try_into().unwrap(), default: None }])]
+    #[case("abc{def}", &[Token::Literal("abc".to_string()), Token::Variable { key: "def".try_into().unwrap(), default: None }])]
+    #[case("abc {def}", &[Token::Literal("abc ".to_string()), Token::Variable { key: "def".try_into().unwrap(), default: None }])]
+    #[case("{abc}def", &[Token::Variable { key: "abc".try_into().unwrap(), default: None }, Token::Literal("def".to_string())])]
+    #[case("{abc}{def}", &[Token::Variable { key: "abc".try_into().unwrap(), default: None }, Token::Variable { key: "def".try_into().unwrap(), default: None }])]
+    #[case("{ext|exr}", &[Token::Variable { key: "ext".try_into().unwrap(), default: Some("exr".to_string()) }])]
+    #[case("{ ext | exr }", &[Token::Variable { key: "ext".try_into().unwrap(), default: Some("exr".to_string()) }])]
+    #[case("{thing=unknown}", &[Token::Variable { key: "thing".try_into().unwrap(), default: Some("unknown".to_string()) }])]
+    #[case("{ thing = unknown }", &[Token::Variable { key: "thing".try_into().unwrap(), default: Some("unknown".to_string()) }])]
+    #[case("{thing|a=b}", &[Token::Variable { key: "thing".try_into().unwrap(), default: Some("a=b".to_string()) }])]
+    #[case("{name}{?.ext}", &[Token::Variable { key: "name".try_into().unwrap(), default: None }, Token::OptionalExtension { key: "ext".try_into().unwrap() }])]
+    #[case("{ ?.ext }", &[Token::OptionalExtension { key: "ext".try_into().unwrap() }])]
+    #[case("{**}", &[Token::CatchAll])]
     fn test_tokens_new_success(#[case] input: &str, #[case] expected: &[Token]) {
         let result = Tokens::new(&input).unwrap();
         assert_eq!(result.tokens, expected);
@@ -577,6 +948,15 @@ mod tests {
     #[case("{abc.def.}", "Invalid variable")]
     #[case("{abc.def..}", "Invalid variable")]
     #[case("{{abc}}", "Invalid variable")]
+    #[case("{|exr}", "Invalid variable")]
+    #[case("{ext|}", "Missing default")]
+    #[case("{ext|exr|tif}", "Invalid default")]
+    #[case("{=unknown}", "Invalid variable")]
+    #[case("{thing=}", "Missing default")]
+    #[case("{thing=a|b}", "Invalid default")]
+    #[case("{thing.=unknown}", "Invalid variable")]
+    #[case("{?.}", "Invalid variable")]
+    #[case("{?.ext|exr}", "Invalid variable")]
     fn test_tokens_new_failure(#[case] input: &str, #[case] expected: &str) {
         let result = Tokens::new(&input).unwrap_err();
 
@@ -604,11 +984,19 @@ mod tests {
             let mut resolvers = Resolvers::new();
             resolvers.insert(
                 "test_str".try_into().unwrap(),
-                Resolver::String { pattern: None },
+                Resolver::String {
+                    pattern: None,
+                    percent_encoded: false,
+                },
             );
             resolvers.insert(
                 "test_int".try_into().unwrap(),
-                Resolver::Integer { padding: 3 },
+                Resolver::Integer {
+                    padding: 3,
+                    group_separator: None,
+                    min: None,
+                    max: None,
+                },
             );
             resolvers
         };
@@ -619,6 +1007,116 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest::rstest]
+    #[case(Some("jpg"), "image.jpg")]
+    #[case(None, "image.exr")]
+    fn test_tokens_draw_default_success(#[case] ext: Option<&str>, #[case] expected: &str) {
+        let tokens = Tokens::new(&"image.{ext|exr}").unwrap();
+
+        let mut fields = PathAttributes::new();
+
+        if let Some(ext) = ext {
+            fields.insert("ext".try_into().unwrap(), ext.into());
+        }
+
+        let mut result = String::new();
+        tokens
+            .draw(&mut result, &fields, &Resolvers::new())
+            .unwrap();
+
+        assert_eq!(result, expected);
+        assert!(tokens.is_resolved_by(&fields));
+        assert_eq!(tokens.to_string(), "image.{ext|exr}");
+    }
+
+    #[rstest::rstest]
+    #[case(Some("asset"), "thing_asset")]
+    #[case(None, "thing_unknown")]
+    fn test_tokens_draw_equals_default_success(
+        #[case] thing: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let tokens = Tokens::new(&"thing_{thing=unknown}").unwrap();
+
+        let mut fields = PathAttributes::new();
+
+        if let Some(thing) = thing {
+            fields.insert("thing".try_into().unwrap(), thing.into());
+        }
+
+        let mut result = String::new();
+        tokens
+            .draw(&mut result, &fields, &Resolvers::new())
+            .unwrap();
+
+        assert_eq!(result, expected);
+        assert!(tokens.is_resolved_by(&fields));
+        assert_eq!(tokens.to_string(), "thing_{thing|unknown}");
+    }
+
+    #[rstest::rstest]
+    #[case(0, 12345, "12_345")]
+    #[case(0, 1000, "1_000")]
+    #[case(0, 999, "999")]
+    #[case(7, 12345, "0_012_345")]
+    #[case(0, 65535, "65_535")]
+    fn test_tokens_draw_group_separator_success(
+        #[case] padding: u8,
+        #[case] value: u16,
+        #[case] expected: &str,
+    ) {
+        let tokens = Tokens::new(&"{frame}").unwrap();
+        let mut fields = PathAttributes::new();
+        fields.insert("frame".try_into().unwrap(), value.into());
+        let resolver = Resolver::Integer {
+            padding,
+            group_separator: Some('_'),
+            min: None,
+            max: None,
+        };
+        let mut resolvers = Resolvers::new();
+        resolvers.insert("frame".try_into().unwrap(), resolver.clone());
+
+        let mut result = String::new();
+        tokens.draw(&mut result, &fields, &resolvers).unwrap();
+
+        assert_eq!(result, expected);
+
+        // The drawn value must be parsed back into the same integer.
+        let mut pattern = String::from("^");
+        tokens.draw_regex_pattern(&mut pattern, &resolvers).unwrap();
+        pattern.push('$');
+        let captures = regex::Regex::new(&pattern)
+            .unwrap()
+            .captures(&result)
+            .unwrap();
+
+        assert_eq!(
+            resolver.to_path_value(&captures[1]).unwrap(),
+            PathValue::Integer(value)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(Resolver::Integer { padding: 3, group_separator: None, min: None, max: None }, "{version|010}", None)]
+    #[case(Resolver::Integer { padding: 3, group_separator: None, min: None, max: None }, "{version|abc}", Some("Default \"abc\" is invalid for \"version\"."))]
+    #[case(Resolver::Integer { padding: 3, group_separator: None, min: None, max: None }, "{version|99999999}", Some("Default \"99999999\" is invalid for \"version\"."))]
+    #[case(Resolver::String { pattern: Some(crate::cache::regex("[a-z]+").unwrap()), percent_encoded: false }, "{version|abc}", None)]
+    #[case(Resolver::String { pattern: Some(crate::cache::regex("[a-z]+").unwrap()), percent_encoded: false }, "{version|abc1}", Some("Default \"abc1\" is invalid for \"version\"."))]
+    fn test_tokens_validate_defaults(
+        #[case] resolver: Resolver,
+        #[case] input: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let tokens = Tokens::new(&input).unwrap();
+        let mut resolvers = Resolvers::new();
+        resolvers.insert("version".try_into().unwrap(), resolver);
+
+        let result = tokens.validate_defaults(&resolvers);
+
+        assert_eq!(result.err().map(|err| err.to_string()).as_deref(), expected);
+    }
+
     #[rstest::rstest]
     #[case("{test_str}", "test_str")]
     #[case("{test_int}", "test_int")]
@@ -643,14 +1141,14 @@ mod tests {
     #[rstest::rstest]
     #[case("", &[])]
     #[case("abc", &[Token::Literal("abc".to_string())])]
-    #[case("{abc}", &[Token::Variable("abc".try_into().unwrap())])]
-    #[case("{abc123}", &[Token::Variable("abc123".try_into().unwrap())])]
-    #[case("{abc.def}", &[Token::Variable("abc.def".try_into().unwrap())])]
-    #[case("{ abc }", &[Token::Variable("abc".try_into().unwrap())])]
-    #[case("abc{def}", &[Token::Literal("abc".to_string()), Token::Variable("def".try_into().unwrap())])]
-    #[case("abc {def}", &[Token::Literal("abc ".to_string()), Token::Variable("def".try_into().unwrap())])]
-    #[case("{abc}def", &[Token::Variable("abc".try_into().unwrap()), Token::Literal("def".to_string())])]
-    #[case("{abc}{def}", &[Token::Variable("abc".try_into().unwrap()), Token::Variable("def".try_into().unwrap())])]
+    #[case("{abc}", &[Token::Variable { key: "abc".try_into().unwrap(), default: None }])]
+    #[case("{abc123}", &[Token::Variable { key: "abc123".try_into().unwrap(), default: None }])]
+    #[case("{abc.def}", &[Token::Variable { key: "abc.def".try_into().unwrap(), default: None }])]
+    #[case("{ abc }", &[Token::Variable { key: "abc".try_into().unwrap(), default: None }])]
+    #[case("abc{def}", &[Token::Literal("abc".to_string()), Token::Variable { key: "def".try_into().unwrap(), default: None }])]
+    #[case("abc {def}", &[Token::Literal("abc ".to_string()), Token::Variable { key: "def".try_into().unwrap(), default: None }])]
+    #[case("{abc}def", &[Token::Variable { key: "abc".try_into().unwrap(), default: None }, Token::Literal("def".to_string())])]
+    #[case("{abc}{def}", &[Token::Variable { key: "abc".try_into().unwrap(), default: None }, Token::Variable { key: "def".try_into().unwrap(), default: None }])]
     fn test_tokens_try_from_success(#[case] input: &str, #[case] expected: &[Token]) {
         // From<&str>
         let tokens = Tokens::try_from(input).unwrap();
@@ -1,4 +1,4 @@
-use crate::types::TemplateAttributes;
+use crate::types::{FieldKey, PathAttributes, TemplateAttributes, TemplateObject};
 
 macro_rules! impl_from {
     ($($e:ty: $t:ty => $v:ident),+ $(,)?) => {
@@ -11,12 +11,24 @@ macro_rules! impl_from {
 }
 
 /// A value for a path.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+///
+/// Floats are compared by their bits, so `0.0` and `-0.0` are different values, the same as
+/// they are different when drawn into a path.
+///
+/// The enum is non-exhaustive, since the variants depend on the enabled features, such as the date
+/// variant with the `date` feature.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub enum PathValue {
     /// An integer.
     Integer(u16),
+    /// A float.
+    Float(f64),
     /// A string.
     String(String),
+    /// A date.
+    #[cfg(feature = "date")]
+    Date(chrono::NaiveDate),
 }
 
 impl_from!(
@@ -24,8 +36,246 @@ impl_from!(
     PathValue: String => String,
     PathValue: u8 => Integer,
     PathValue: u16 => Integer,
+    PathValue: f32 => Float,
+    PathValue: f64 => Float,
 );
 
+#[cfg(feature = "date")]
+impl_from!(
+    PathValue: chrono::NaiveDate => Date,
+);
+
+impl PartialEq for PathValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Integer(value), Self::Integer(other_value)) => value == other_value,
+            (Self::Float(value), Self::Float(other_value)) => {
+                value.to_bits() == other_value.to_bits()
+            }
+            (Self::String(value), Self::String(other_value)) => value == other_value,
+            #[cfg(feature = "date")]
+            (Self::Date(value), Self::Date(other_value)) => value == other_value,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for PathValue {}
+
+impl std::hash::Hash for PathValue {
+    /// Hash the value by its kind and value, using the bits of a float the same as [PartialEq].
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Self::Integer(value) => value.hash(state),
+            Self::Float(value) => value.to_bits().hash(state),
+            Self::String(value) => value.hash(state),
+            #[cfg(feature = "date")]
+            Self::Date(value) => value.hash(state),
+        }
+    }
+}
+
+impl std::fmt::Display for PathValue {
+    /// Write the bare value. Use [to_drawn_string](Self::to_drawn_string) for the value as it
+    /// would appear in a path.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{}", value),
+            Self::Float(value) => write!(f, "{}", value),
+            Self::String(value) => write!(f, "{}", value),
+            #[cfg(feature = "date")]
+            Self::Date(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl PathValue {
+    /// Draw the value as it would appear in a path with the resolver, such as applying the zero
+    /// padding of an integer resolver.
+    ///
+    /// If the resolver does not apply to the value, then the bare value is returned. Use
+    /// [Config::parse_field](crate::Config::parse_field) to parse a drawn value back into a path
+    /// value.
+    pub fn to_drawn_string(&self, resolver: &crate::Resolver) -> String {
+        match (self, resolver) {
+            (
+                Self::Integer(value),
+                crate::Resolver::Integer {
+                    padding,
+                    group_separator,
+                    ..
+                },
+            ) => draw_integer(*value, *padding, *group_separator),
+            (Self::Float(value), crate::Resolver::Float { precision }) => {
+                draw_float(*value, *precision)
+            }
+            #[cfg(feature = "date")]
+            (Self::Date(value), crate::Resolver::Date { format }) => {
+                draw_date(value, format).unwrap_or_else(|_| self.to_string())
+            }
+            (
+                Self::String(value),
+                crate::Resolver::String {
+                    percent_encoded: true,
+                    ..
+                },
+            ) => crate::types::resolver::percent_encode(value),
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// The path fields to resolve a path with.
+///
+/// This is a thin wrapper around a map of field keys to path values, with an
+/// [insert](Self::insert) that converts the key and value. It dereferences to the map, so it can
+/// be passed to any function that takes the path fields, such as [get_path](crate::get_path).
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::PathFields;
+/// let mut fields = PathFields::new();
+/// fields.insert("shot", "sh010").unwrap();
+/// fields.insert("version", 3u16).unwrap();
+///
+/// assert_eq!(fields.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathFields(PathAttributes);
+
+impl PathFields {
+    /// Create an empty set of path fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a field, replacing the previous value if there was one.
+    ///
+    /// # Errors
+    ///
+    /// - The key must be a valid field key.
+    pub fn insert(
+        &mut self,
+        key: impl TryInto<FieldKey, Error = crate::Error>,
+        value: impl Into<PathValue>,
+    ) -> Result<(), crate::Error> {
+        self.0.insert(key.try_into()?, value.into());
+        Ok(())
+    }
+}
+
+impl std::ops::Deref for PathFields {
+    type Target = PathAttributes;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for PathFields {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<PathAttributes> for PathFields {
+    fn from(value: PathAttributes) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PathFields> for PathAttributes {
+    fn from(value: PathFields) -> Self {
+        value.0
+    }
+}
+
+impl FromIterator<(FieldKey, PathValue)> for PathFields {
+    fn from_iter<T: IntoIterator<Item = (FieldKey, PathValue)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Draw the integer with the zero padding, and the separator between every three digits if there
+/// is one.
+pub(crate) fn draw_integer(value: u16, padding: u8, group_separator: Option<char>) -> String {
+    let digits = format!("{:0width$}", value, width = padding as usize);
+
+    match group_separator {
+        Some(group_separator) => group_digits(&digits, group_separator),
+        None => digits,
+    }
+}
+
+pub(crate) fn draw_float(value: f64, precision: u8) -> String {
+    format!("{:.precision$}", value, precision = precision as usize)
+}
+
+/// Draw a date with a strftime-style format.
+#[cfg(feature = "date")]
+pub(crate) fn draw_date(value: &chrono::NaiveDate, format: &str) -> Result<String, crate::Error> {
+    use std::fmt::Write;
+
+    let mut drawn = String::new();
+
+    // Writing the format returns an error for an invalid format, instead of panicking like
+    // `to_string`.
+    match write!(drawn, "{}", value.format(format)) {
+        Ok(_) => Ok(drawn),
+        Err(_) => Err(crate::Error::new(format!(
+            "The date {value} cannot be drawn with the format {format:?}."
+        ))),
+    }
+}
+
+/// Insert the separator between every three digits, counting from the right.
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index != 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+/// A segment of a matched path.
+///
+/// This is returned by [get_fields_segments](crate::get_fields_segments).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathSegment {
+    /// Literal text, including any path separators.
+    Literal(String),
+    /// A field and the value that was extracted from the path.
+    Field(crate::FieldKey, PathValue),
+}
+
+/// The result of matching a path, with the reason when the path does not match.
+///
+/// This is returned by [get_fields_explain](crate::get_fields_explain).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GetFieldsResult {
+    /// The path matched, with the fields that were extracted from the path.
+    Match(std::collections::HashMap<crate::FieldKey, PathValue>),
+    /// The path did not match.
+    Mismatch {
+        /// The index of the first path component that did not match, where the root of an
+        /// absolute path, such as `/`, is the first component.
+        index: usize,
+        /// The regex that the component was expected to match.
+        pattern: String,
+        /// The component from the path.
+        segment: String,
+    },
+}
+
 /// A value for a template.
 ///
 /// This is similar to a JSON type.
@@ -44,7 +294,9 @@ pub enum TemplateValue {
     /// An array of template values.
     Array(Vec<TemplateValue>),
     /// An object or mapping of template values.
-    Object(TemplateAttributes),
+    ///
+    /// The keys keep their insertion order, so iterating over an object is deterministic.
+    Object(TemplateObject),
 }
 
 impl_from!(
@@ -61,9 +313,107 @@ impl_from!(
     TemplateValue: &str => String,
     TemplateValue: String => String,
     TemplateValue: Vec<TemplateValue> => Array,
-    TemplateValue: TemplateAttributes => Object,
+    TemplateValue: TemplateObject => Object,
 );
 
+impl From<TemplateAttributes> for TemplateValue {
+    /// Convert an unordered map into an object. The keys are sorted so the order is deterministic.
+    fn from(value: TemplateAttributes) -> Self {
+        let mut items = value.into_iter().collect::<Vec<_>>();
+        items.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Self::Object(items.into_iter().collect())
+    }
+}
+
+impl TemplateValue {
+    /// Convert any serializable value into a template value.
+    ///
+    /// This is useful for dropping a caller's own types into the template fields without building
+    /// the objects by hand. Structs and maps become objects, sequences and tuples become arrays,
+    /// and enum variants with data become an object with the variant name as the only key.
+    ///
+    /// # Errors
+    ///
+    /// - Map keys and struct field names must be valid field keys.
+    /// - Integers must fit into an `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use openpathresolver::TemplateValue;
+    /// #[derive(serde::Serialize)]
+    /// struct Shot {
+    ///     name: String,
+    ///     frames: (u32, u32),
+    /// }
+    ///
+    /// let value = TemplateValue::from_serializable(&Shot {
+    ///     name: "sh010".into(),
+    ///     frames: (1001, 1100),
+    /// })
+    /// .unwrap();
+    ///
+    /// assert!(matches!(value, TemplateValue::Object(_)));
+    /// ```
+    pub fn from_serializable<T: serde::Serialize + ?Sized>(
+        value: &T,
+    ) -> Result<Self, crate::Error> {
+        value.serialize(crate::types::serializer::TemplateValueSerializer)
+    }
+
+    /// Convert the template value into a path value.
+    ///
+    /// This is useful for callers that keep one field map for both paths and templates. See
+    /// [get_path_from_template_fields](crate::get_path_from_template_fields).
+    ///
+    /// # Errors
+    ///
+    /// - Integers must fit into a path integer.
+    /// - Only integers, floats, and strings can be converted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use openpathresolver::{PathValue, TemplateValue};
+    /// assert_eq!(
+    ///     TemplateValue::Integer(7).to_path_value().unwrap(),
+    ///     PathValue::Integer(7)
+    /// );
+    /// assert!(TemplateValue::Bool(true).to_path_value().is_err());
+    /// ```
+    pub fn to_path_value(&self) -> Result<PathValue, crate::Error> {
+        self.to_path_value_for(None)
+    }
+
+    /// Convert the template value into a path value, naming the field in the error if given.
+    pub(crate) fn to_path_value_for(
+        &self,
+        key: Option<&crate::FieldKey>,
+    ) -> Result<PathValue, crate::Error> {
+        let field = match key {
+            Some(key) => format!(" for {:?}", key.as_str()),
+            None => String::new(),
+        };
+
+        match self {
+            Self::Integer(value) => match u16::try_from(*value) {
+                Ok(value) => Ok(PathValue::Integer(value)),
+                Err(_) => Err(crate::Error::new(format!(
+                    "Template value {value:?}{field} is out of range for a path value."
+                ))),
+            },
+            Self::Float(value) => Ok(PathValue::Float(*value)),
+            Self::String(value) => Ok(PathValue::String(value.clone())),
+            Self::None | Self::Bool(_) | Self::Array(_) | Self::Object(_) => {
+                Err(crate::Error::new(format!(
+                    "Template value {self:?}{field} cannot be used as a path value."
+                )))
+            }
+        }
+    }
+}
+
 impl TryFrom<u64> for TemplateValue {
     type Error = crate::Error;
 
@@ -117,3 +467,201 @@ impl TryFrom<u64> for MetadataValue {
         Ok(Self::Integer(value.try_into()?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldKey, Resolver};
+
+    #[rstest::rstest]
+    #[case(PathValue::Integer(7), "7")]
+    #[case(PathValue::Integer(1234), "1234")]
+    #[case(PathValue::Float(1.5), "1.5")]
+    #[case(PathValue::String("value".into()), "value")]
+    fn test_path_value_display(#[case] value: PathValue, #[case] expected: &str) {
+        assert_eq!(value.to_string(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(PathValue::Integer(7), Resolver::Integer { padding: 3, group_separator: None, min: None, max: None }, "007")]
+    #[case(PathValue::Integer(1234), Resolver::Integer { padding: 3, group_separator: None, min: None, max: None }, "1234")]
+    #[case(PathValue::Integer(12345), Resolver::Integer { padding: 0, group_separator: Some('_'), min: None, max: None }, "12_345")]
+    #[case(PathValue::Integer(7), Resolver::Default, "7")]
+    #[case(PathValue::Float(1.5), Resolver::Float { precision: 3 }, "1.500")]
+    #[case(PathValue::Float(2.0), Resolver::Float { precision: 0 }, "2")]
+    #[case(PathValue::Float(1.5), Resolver::Default, "1.5")]
+    #[case(PathValue::String("value".into()), Resolver::Default, "value")]
+    fn test_path_value_to_drawn_string(
+        #[case] value: PathValue,
+        #[case] resolver: Resolver,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(value.to_drawn_string(&resolver), expected);
+    }
+
+    #[test]
+    fn test_path_fields_insert_success() {
+        let mut fields = PathFields::new();
+        fields.insert("shot", "sh010").unwrap();
+        fields.insert("version", 3u16).unwrap();
+        fields.insert("version", 4u16).unwrap();
+
+        let expected: PathAttributes = [
+            (FieldKey::new("shot").unwrap(), PathValue::from("sh010")),
+            (FieldKey::new("version").unwrap(), PathValue::Integer(4)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(*fields, expected);
+        assert_eq!(PathAttributes::from(fields), expected);
+    }
+
+    #[test]
+    fn test_path_fields_insert_failure_invalid_key() {
+        let mut fields = PathFields::new();
+
+        assert!(fields.insert("", "value").is_err());
+        assert!(fields.is_empty());
+    }
+
+    #[test]
+    fn test_template_value_from_serializable_success() {
+        #[derive(serde::Serialize)]
+        enum Status {
+            Final,
+            Review { notes: String },
+        }
+
+        #[derive(serde::Serialize)]
+        struct Shot {
+            name: String,
+            frames: (u32, u32),
+            artist: Option<String>,
+            tags: Vec<&'static str>,
+            status: Status,
+            previous: Status,
+        }
+
+        let value = TemplateValue::from_serializable(&Shot {
+            name: "sh010".into(),
+            frames: (1001, 1100),
+            artist: None,
+            tags: vec!["hero"],
+            status: Status::Review {
+                notes: "more smoke".into(),
+            },
+            previous: Status::Final,
+        })
+        .unwrap();
+
+        let mut review = TemplateObject::new();
+        review.insert(FieldKey::new("notes").unwrap(), "more smoke".into());
+        let mut status = TemplateObject::new();
+        status.insert(FieldKey::new("review").unwrap(), review.into());
+        let mut expected = TemplateObject::new();
+        expected.insert(FieldKey::new("name").unwrap(), "sh010".into());
+        expected.insert(
+            FieldKey::new("frames").unwrap(),
+            vec![1001.into(), 1100.into()].into(),
+        );
+        expected.insert(FieldKey::new("artist").unwrap(), TemplateValue::None);
+        expected.insert(FieldKey::new("tags").unwrap(), vec!["hero".into()].into());
+        expected.insert(FieldKey::new("status").unwrap(), status.into());
+        expected.insert(FieldKey::new("previous").unwrap(), "Final".into());
+
+        assert_eq!(value, TemplateValue::Object(expected));
+    }
+
+    #[rstest::rstest]
+    #[case(TemplateValue::from_serializable(&u64::MAX), "Error while converting integer type.")]
+    #[case(
+        TemplateValue::from_serializable(&std::collections::BTreeMap::from([("not a key", 1)])),
+        "Invalid field key: \"not a key\""
+    )]
+    #[case(
+        TemplateValue::from_serializable(&std::collections::BTreeMap::from([(1, 1)])),
+        "Object keys must be strings, found Integer(1)"
+    )]
+    fn test_template_value_from_serializable_failure(
+        #[case] result: Result<TemplateValue, crate::Error>,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(result.unwrap_err().to_string(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(TemplateValue::Integer(0), PathValue::Integer(0))]
+    #[case(TemplateValue::Integer(65535), PathValue::Integer(65535))]
+    #[case(TemplateValue::Float(1.5), PathValue::Float(1.5))]
+    #[case(TemplateValue::String("sh010".into()), PathValue::String("sh010".into()))]
+    fn test_template_value_to_path_value_success(
+        #[case] value: TemplateValue,
+        #[case] expected: PathValue,
+    ) {
+        assert_eq!(value.to_path_value().unwrap(), expected);
+    }
+
+    #[rstest::rstest]
+    #[case(TemplateValue::Integer(-1), "Template value -1 is out of range for a path value.")]
+    #[case(
+        TemplateValue::Integer(65536),
+        "Template value 65536 is out of range for a path value."
+    )]
+    #[case(
+        TemplateValue::None,
+        "Template value None cannot be used as a path value."
+    )]
+    #[case(
+        TemplateValue::Bool(true),
+        "Template value Bool(true) cannot be used as a path value."
+    )]
+    #[case(
+        TemplateValue::Array(Vec::new()),
+        "Template value Array([]) cannot be used as a path value."
+    )]
+    #[case(
+        TemplateValue::Object(TemplateObject::new()),
+        "Template value Object({}) cannot be used as a path value."
+    )]
+    fn test_template_value_to_path_value_failure(
+        #[case] value: TemplateValue,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(value.to_path_value().unwrap_err().to_string(), expected);
+    }
+
+    #[test]
+    fn test_template_value_object_preserves_insertion_order() {
+        let mut object = TemplateObject::new();
+        object.insert(FieldKey::new("zeta").unwrap(), "z".into());
+        object.insert(FieldKey::new("alpha").unwrap(), "a".into());
+        object.insert(FieldKey::new("mid").unwrap(), "m".into());
+
+        let value = TemplateValue::from(object);
+
+        for _ in 0..10 {
+            let TemplateValue::Object(object) = value.clone() else {
+                panic!("Expected an object.");
+            };
+            let keys = object.keys().map(|k| k.as_str()).collect::<Vec<_>>();
+
+            assert_eq!(keys, vec!["zeta", "alpha", "mid"]);
+        }
+    }
+
+    #[test]
+    fn test_template_value_object_from_hash_map_is_sorted() {
+        let mut attributes = TemplateAttributes::new();
+        attributes.insert(FieldKey::new("zeta").unwrap(), "z".into());
+        attributes.insert(FieldKey::new("alpha").unwrap(), "a".into());
+        attributes.insert(FieldKey::new("mid").unwrap(), "m".into());
+
+        let TemplateValue::Object(object) = TemplateValue::from(attributes) else {
+            panic!("Expected an object.");
+        };
+        let keys = object.keys().map(|k| k.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(keys, vec!["alpha", "mid", "zeta"]);
+    }
+}
@@ -3,6 +3,7 @@
 /// The [create_workspace] function hands over the responsibility of creating files and folders to
 /// the type that implements this trait. The resolver does not assume anything about the
 /// permissions or ownership aside from "this path is owned by a user" or "this path is read only".
+#[cfg(feature = "std")]
 #[async_trait::async_trait]
 pub trait CreateWorkspaceIoFunction {
     /// The function that gets called by the [create_workspace] function when building the
@@ -21,6 +22,10 @@ pub trait CreateWorkspaceIoFunction {
 /// not. In other words, this will create paths that can be resolved with the path fields, but
 /// other paths will not be created.
 ///
+/// Parent paths are always created before their children. Paths that share the same parent are
+/// created in the order of their [order](crate::PathItemArgs::order), from lowest to highest. Paths
+/// with the same order may be created concurrently.
+///
 /// # Example
 ///
 /// ```rust
@@ -50,6 +55,8 @@ pub trait CreateWorkspaceIoFunction {
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
@@ -61,6 +68,8 @@ pub trait CreateWorkspaceIoFunction {
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
@@ -72,6 +81,8 @@ pub trait CreateWorkspaceIoFunction {
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
@@ -101,47 +112,543 @@ pub trait CreateWorkspaceIoFunction {
 /// .unwrap();
 /// # }
 /// ```
+#[cfg(feature = "std")]
 pub async fn create_workspace<Func: CreateWorkspaceIoFunction + Send + Sync + 'static>(
     config: std::sync::Arc<crate::Config>,
     path_fields: &crate::types::PathAttributes,
     template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
     io_function: Func,
 ) -> Result<(), crate::Error> {
+    create_workspace_with_options(
+        config,
+        path_fields,
+        template_fields,
+        io_function,
+        &crate::CreateWorkspaceOptions::default(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Build a workspace, with extra options for how the workspace is created.
+///
+/// This is the same as [create_workspace], but the creation can be controlled with the
+/// [CreateWorkspaceOptions](crate::CreateWorkspaceOptions). If the error mode is
+/// [CollectErrors](crate::CreateWorkspaceErrorMode::CollectErrors), then every path is attempted,
+/// and the paths that failed are returned with their errors. Otherwise, the first error is
+/// returned and the returned list is always empty.
+///
+/// # Errors
+///
+/// - Every key in [require_keys](crate::CreateWorkspaceOptions::require_keys) must resolve to a
+///   path that will be created. This is checked before the IO function is called.
+/// - The errors from the IO function, depending on the error mode.
+/// - If [verify](crate::CreateWorkspaceOptions::verify) is set, every path that the IO function
+///   did not fail on must exist after the workspace is created. The error also lists the paths
+///   that the IO function failed on, since they are not returned.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, create_workspace_with_options, CreateWorkspaceErrorMode, CreateWorkspaceOptions, Owner, PathItemArgs, PathType, Permission, Error, CreateWorkspaceIoFunction, FieldKey, TemplateValue, ResolvedPathItem, Config};
+/// struct Func;
+///
+/// #[async_trait::async_trait]
+/// impl CreateWorkspaceIoFunction for Func {
+///     async fn call(
+///         &self,
+///         _config: std::sync::Arc<Config>,
+///         _template_fields: std::sync::Arc<std::collections::HashMap<FieldKey, TemplateValue>>,
+///         _path_item: ResolvedPathItem,
+///     ) -> Result<(), Error> {
+///         Err(Error::new("Could not create the path."))
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let path_fields = {
+///     let mut fields = std::collections::HashMap::new();
+///     fields.insert("thing".try_into().unwrap(), "value".into());
+///
+///     fields
+/// };
+/// let options = CreateWorkspaceOptions {
+///     error_mode: CreateWorkspaceErrorMode::CollectErrors,
+///     require_keys: Vec::new(),
+///     verify: false,
+/// };
+///
+/// let failures = create_workspace_with_options(
+///     std::sync::Arc::new(config),
+///     &path_fields,
+///     std::sync::Arc::new(std::collections::HashMap::new()),
+///     Func,
+///     &options,
+/// )
+/// .await
+/// .unwrap();
+///
+/// assert_eq!(failures.len(), 4);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub async fn create_workspace_with_options<
+    Func: CreateWorkspaceIoFunction + Send + Sync + 'static,
+>(
+    config: std::sync::Arc<crate::Config>,
+    path_fields: &crate::types::PathAttributes,
+    template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+    io_function: Func,
+    options: &crate::CreateWorkspaceOptions,
+) -> Result<Vec<(crate::ResolvedPathItem, crate::Error)>, crate::Error> {
     let resolved_items = get_workspace(config.as_ref(), path_fields)?;
-    let mut parent_resolved_map = std::collections::BTreeMap::new();
 
-    for resolved_item in &resolved_items {
-        let parent = resolved_item.value.parent();
-        parent_resolved_map
-            .entry(parent)
-            .or_insert(Vec::new())
-            .push(resolved_item.clone());
+    for key in options.require_keys.iter() {
+        let path = match crate::get_path(config.as_ref(), key, path_fields) {
+            Ok(path) => path,
+            Err(error) => {
+                return Err(crate::Error::new(format!(
+                    "Required key {:?} would not be created: {error}",
+                    key.as_str()
+                )));
+            }
+        };
+
+        if !resolved_items.iter().any(|item| item.value == path) {
+            // The path resolved, so the fields are not missing. A composite item is never created,
+            // and otherwise the item is deferred.
+            let reason = if config.composite_map.contains_key(key) {
+                "The key is a composite item, which is not created."
+            } else {
+                "The path is deferred."
+            };
+
+            return Err(crate::Error::new(format!(
+                "Required key {:?} would not be created: {reason}",
+                key.as_str()
+            )));
+        }
     }
 
     let mut workers_set = tokio::task::JoinSet::new();
     let io_function = std::sync::Arc::new(io_function);
+    let mut failures = Vec::new();
 
-    for (_, child_resolved_items) in parent_resolved_map {
-        for resolved_item in child_resolved_items {
+    for batch in workspace_batches(&resolved_items) {
+        for resolved_item in batch {
             let io_function = io_function.clone();
             let config = config.clone();
             let template_fields = template_fields.clone();
             workers_set.spawn(async move {
-                io_function
-                    .call(config, template_fields, resolved_item)
-                    .await
+                let result = io_function
+                    .call(config, template_fields, resolved_item.clone())
+                    .await;
+
+                (resolved_item, result)
             });
         }
 
         while let Some(response) = workers_set.join_next().await {
-            response??;
+            let (resolved_item, result) = response?;
+
+            if let Err(error) = result {
+                match options.error_mode {
+                    crate::CreateWorkspaceErrorMode::FailFast => return Err(error),
+                    crate::CreateWorkspaceErrorMode::CollectErrors => {
+                        failures.push((resolved_item, error))
+                    }
+                }
+            }
+        }
+    }
+
+    if options.verify {
+        let failed_paths = failures
+            .iter()
+            .map(|(failed, _)| failed.value.as_path())
+            .collect::<std::collections::HashSet<_>>();
+        let mut missing = Vec::new();
+
+        for resolved_item in &resolved_items {
+            if failed_paths.contains(resolved_item.value.as_path()) {
+                continue;
+            }
+
+            if !tokio::fs::try_exists(&resolved_item.value).await? {
+                missing.push(format!("{:?}", resolved_item.value));
+            }
+        }
+
+        if !missing.is_empty() {
+            let mut msg = format!("The paths were not created: {}.", missing.join(", "));
+
+            // The collected failures are not returned with the error, so they are reported in it.
+            if !failures.is_empty() {
+                let failed = failures
+                    .iter()
+                    .map(|(failed, error)| format!("{:?} ({error})", failed.value))
+                    .collect::<Vec<_>>();
+                msg.push_str(&format!(" The paths that failed: {}.", failed.join(", ")));
+            }
+
+            return Err(crate::Error::new(msg));
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Get the paths that `create_workspace` would create, in the order that they would be created.
+///
+/// This does not call any IO function or touch the filesystem, so it can be used to preview a
+/// workspace, such as in a confirmation dialog. The deferred paths are skipped the same way as
+/// [get_workspace], and the paths are ordered so a parent is always before its children, and the
+/// paths in the same directory are sorted by their order. `create_workspace` creates the paths
+/// that share a parent and an order at the same time, so those may be created in any order.
+///
+/// # Errors
+///
+/// - The same errors as [get_workspace].
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, plan_workspace, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let fields = {
+///     let mut fields = std::collections::HashMap::new();
+///     fields.insert("thing".try_into().unwrap(), "value".into());
+///
+///     fields
+/// };
+///
+/// let plan = plan_workspace(&config, &fields).unwrap();
+///
+/// assert_eq!(plan.last().unwrap().value(), std::path::Path::new("/path/to/value"));
+/// ```
+pub fn plan_workspace(
+    config: &crate::Config,
+    path_fields: &crate::types::PathAttributes,
+) -> Result<Vec<crate::ResolvedPathItem>, crate::Error> {
+    let resolved_items = get_workspace(config, path_fields)?;
+
+    Ok(workspace_batches(&resolved_items)
+        .into_iter()
+        .flatten()
+        .collect())
+}
+
+/// Group the resolved items into the batches that are created at the same time.
+///
+/// The items are grouped by their parent path, and then by their order. Since a parent path is
+/// sorted before the paths that it contains, a parent is always in an earlier batch than its
+/// children.
+fn workspace_batches(
+    resolved_items: &[crate::ResolvedPathItem],
+) -> Vec<Vec<crate::ResolvedPathItem>> {
+    let mut parent_resolved_map = std::collections::BTreeMap::new();
+
+    for resolved_item in resolved_items {
+        let parent = resolved_item.value.parent();
+        parent_resolved_map
+            .entry(parent)
+            .or_insert(std::collections::BTreeMap::new())
+            .entry(resolved_item.order)
+            .or_insert(Vec::new())
+            .push(resolved_item.clone());
+    }
+
+    parent_resolved_map
+        .into_values()
+        .flat_map(|order_resolved_items| order_resolved_items.into_values())
+        .collect()
+}
+
+/// Create the path of a single key, and the parent paths that it needs.
+///
+/// This is the same as [create_workspace], but only the paths from the root to the key's path are
+/// created, one at a time from the parent to the child. The other branches of the workspace are
+/// not resolved or touched, so they may be missing fields. The paths are resolved the same way as
+/// [get_workspace], so the permissions and owners are the same as when the whole workspace is
+/// created.
+///
+/// # Errors
+///
+/// - The key needs to be in the input config struct, and be resolved by the path fields.
+/// - The key's path must not be deferred.
+/// - The errors from the IO function. The first error stops the remaining paths from being
+///   created.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, create_path, Owner, PathItemArgs, PathType, Permission, Error, CreateWorkspaceIoFunction, FieldKey, TemplateValue, ResolvedPathItem, Config};
+/// struct Func;
+///
+/// #[async_trait::async_trait]
+/// impl CreateWorkspaceIoFunction for Func {
+///     async fn call(
+///         &self,
+///         _config: std::sync::Arc<Config>,
+///         _template_fields: std::sync::Arc<std::collections::HashMap<FieldKey, TemplateValue>>,
+///         _path_item: ResolvedPathItem,
+///     ) -> Result<(), Error> {
+///         Ok(())
+///     }
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let path_fields = {
+///     let mut fields = std::collections::HashMap::new();
+///     fields.insert("thing".try_into().unwrap(), "value".into());
+///
+///     fields
+/// };
+///
+/// create_path(
+///     std::sync::Arc::new(config),
+///     "key",
+///     &path_fields,
+///     std::sync::Arc::new(std::collections::HashMap::new()),
+///     Func,
+/// )
+/// .await
+/// .unwrap();
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub async fn create_path<Func: CreateWorkspaceIoFunction + Send + Sync + 'static>(
+    config: std::sync::Arc<crate::Config>,
+    key: impl TryInto<crate::FieldKey, Error = crate::Error>,
+    path_fields: &crate::types::PathAttributes,
+    template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+    io_function: Func,
+) -> Result<(), crate::Error> {
+    let key = key.try_into()?;
+    crate::get_path(config.as_ref(), &key, path_fields)?;
+
+    let chain = match config.chain_map.get(&key) {
+        Some(chain) => chain,
+        None => {
+            return Err(crate::Error::new(format!(
+                "The path for {:?} is not created, because it is a composite item.",
+                key.as_str()
+            )));
         }
+    };
+    let path_fields = config.with_field_defaults(path_fields);
+    let path_fields = path_fields.as_ref();
+
+    // Only the key's own chain is resolved, so the other branches of the workspace can be missing
+    // fields. The ancestors of a path that is not deferred are never deferred.
+    let index = chain[chain.len() - 1];
+
+    if is_deferred(
+        config.as_ref(),
+        &config.items[index],
+        path_fields,
+        index,
+        &parent_children_map(config.as_ref()),
+        &mut std::collections::HashMap::new(),
+    ) {
+        return Err(crate::Error::new(format!(
+            "The path for {:?} is deferred.",
+            key.as_str()
+        )));
+    }
+
+    let mut resolved_items = Vec::with_capacity(chain.len());
+
+    for index in chain.iter() {
+        let item = &config.items[*index];
+        let resolved_item = match resolved_items.last() {
+            Some(parent_resolved_item) => resolve_item(
+                config.as_ref(),
+                parent_resolved_item,
+                item,
+                *index,
+                path_fields,
+                false,
+            )?,
+            None => resolve_item(
+                config.as_ref(),
+                &root_parent_item(config.as_ref(), item, *index),
+                item,
+                *index,
+                path_fields,
+                false,
+            )?,
+        };
+        resolved_items.push(resolved_item);
+    }
+
+    // The chain is ordered from the root, so the parents are always created first.
+    for resolved_item in resolved_items {
+        io_function
+            .call(config.clone(), template_fields.clone(), resolved_item)
+            .await?;
     }
 
     Ok(())
 }
 
-/// Get all of the path items that would be created with the [create_workspace] function.
+/// The outcome of [write_if_changed].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteStatus {
+    /// The file did not exist or had different contents, so it was written.
+    Written,
+    /// The file already had the same contents, so it was not touched.
+    Unchanged,
+}
+
+/// Write the contents to the path, unless the file already has the same contents.
+///
+/// This is a helper for [CreateWorkspaceIoFunction] implementations that render
+/// [FileTemplate](crate::PathType::FileTemplate) items. The IO function renders the template into
+/// a buffer and hands it to this function, which compares the rendered contents with the existing
+/// file. If the contents are the same, the file is not rewritten, so running [create_workspace]
+/// again does not change the file's modified time.
+///
+/// # Errors
+///
+/// - The existing file could not be read, unless it does not exist.
+/// - The file could not be written.
+#[cfg(feature = "std")]
+pub fn write_if_changed(
+    path: impl AsRef<std::path::Path>,
+    contents: impl AsRef<[u8]>,
+) -> Result<WriteStatus, crate::Error> {
+    let path = path.as_ref();
+    let contents = contents.as_ref();
+
+    match std::fs::read(path) {
+        Ok(existing) if existing == contents => return Ok(WriteStatus::Unchanged),
+        Ok(_) => (),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => (),
+        Err(error) => return Err(error.into()),
+    }
+
+    std::fs::write(path, contents)?;
+
+    Ok(WriteStatus::Written)
+}
+
+/// A path whose permissions on disk do not match its declared [Permission](crate::Permission).
+///
+/// This is reported by [audit_workspace].
+#[cfg(all(unix, feature = "std"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermissionMismatch {
+    /// The permission declared in the config.
+    pub expected: crate::Permission,
+    /// The mode bits of the path on disk, such as `0o755`.
+    pub actual_mode: u32,
+}
+
+/// Compare the permissions of the existing workspace paths with the permissions in the config.
+///
+/// This resolves the paths with [get_workspace], and then checks the mode of every path that
+/// exists. A [ReadOnly](crate::Permission::ReadOnly) path must not have any write bits, and a
+/// [ReadWrite](crate::Permission::ReadWrite) path must be writable by its owner. Paths that do not
+/// exist, or that have an inherited or custom permission, are skipped, since there is no mode to
+/// compare them with.
+///
+/// # Errors
+///
+/// - The same errors as [get_workspace].
+/// - The metadata of an existing path could not be read.
+#[cfg(all(unix, feature = "std"))]
+pub fn audit_workspace(
+    config: &crate::Config,
+    path_fields: &crate::types::PathAttributes,
+) -> Result<Vec<(crate::ResolvedPathItem, PermissionMismatch)>, crate::Error> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mut mismatches = Vec::new();
+
+    for item in get_workspace(config, path_fields)? {
+        let is_match: fn(u32) -> bool = match item.permission() {
+            crate::Permission::ReadOnly => |mode| mode & 0o222 == 0,
+            crate::Permission::ReadWrite => |mode| mode & 0o200 != 0,
+            crate::Permission::Inherit | crate::Permission::Custom(_) => continue,
+        };
+        let metadata = match std::fs::metadata(item.value()) {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(error) => return Err(error.into()),
+        };
+        let mode = metadata.permissions().mode() & 0o7777;
+
+        if !is_match(mode) {
+            let expected = item.permission().clone();
+            mismatches.push((
+                item,
+                PermissionMismatch {
+                    expected,
+                    actual_mode: mode,
+                },
+            ));
+        }
+    }
+
+    Ok(mismatches)
+}
+
+/// Get all of the path items that would be created with the `create_workspace` function.
 ///
 /// The only paths that will be returned are paths that can be fully resolved with the given path
 /// fields.
@@ -159,6 +666,8 @@ pub async fn create_workspace<Func: CreateWorkspaceIoFunction + Send + Sync + 's
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
@@ -170,6 +679,8 @@ pub async fn create_workspace<Func: CreateWorkspaceIoFunction + Send + Sync + 's
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
@@ -181,6 +692,8 @@ pub async fn create_workspace<Func: CreateWorkspaceIoFunction + Send + Sync + 's
 ///         owner: Owner::default(),
 ///         path_type: PathType::default(),
 ///         deferred: false,
+///         order: 0,
+///         description: None,
 ///         metadata: std::collections::HashMap::new(),
 ///     })
 ///     .unwrap()
@@ -204,113 +717,24 @@ pub fn get_workspace(
     config: &crate::Config,
     path_fields: &crate::types::PathAttributes,
 ) -> Result<Vec<crate::ResolvedPathItem>, crate::Error> {
-    let mut queue = std::collections::VecDeque::new();
-    let mut parent_children_map = std::collections::HashMap::new();
-
-    for (index, item) in config.items.iter().enumerate() {
-        match item.parent {
-            Some(parent_index) => parent_children_map
-                .entry(parent_index)
-                .or_insert(Vec::new())
-                .push(index),
-            None => queue.push_back((item, index)),
-        };
-    }
+    let path_fields = config.with_field_defaults(path_fields);
+    let path_fields = path_fields.as_ref();
+    let parent_children_map = parent_children_map(config);
 
-    fn is_deferred(
+    #[allow(clippy::too_many_arguments)]
+    fn recursive_build_items(
         config: &crate::Config,
+        parent_resolved_item: &crate::ResolvedPathItem,
         item: &crate::types::PathItem,
-        path_fields: &crate::types::PathAttributes,
         index: usize,
+        path_fields: &crate::types::PathAttributes,
         parent_children_map: &std::collections::HashMap<usize, Vec<usize>>,
-        cache: &mut std::collections::HashMap<usize, bool>,
-    ) -> bool {
-        if let Some(deferred) = cache.get(&index) {
-            return *deferred;
-        }
-
-        fn inner_is_deferred(
-            config: &crate::Config,
-            item: &crate::types::PathItem,
-            path_fields: &crate::types::PathAttributes,
-            index: usize,
-            parent_children_map: &std::collections::HashMap<usize, Vec<usize>>,
-            cache: &mut std::collections::HashMap<usize, bool>,
-        ) -> bool {
-            // Rules for deferring:
-            //  - If the path has a variable token, then it may be deferred if explicitly marked as
-            //    deferred, or cannot be resolved.
-            //  - If the path does not have a variable token, and is not explicitly marked as deferred,
-            //    then it is not deferred.
-            //  - If any of the child paths for this item are not deferred using the above rules, then
-            //    it is not deferred.
-            //  - Otherwise, it is deferred.
-            let child_indexes = parent_children_map.get(&index);
-
-            if let Some(child_indexes) = child_indexes {
-                for child_index in child_indexes.iter() {
-                    let child_item = &config.items[*child_index];
-                    let deferred = is_deferred(
-                        config,
-                        child_item,
-                        path_fields,
-                        *child_index,
-                        parent_children_map,
-                        cache,
-                    );
-
-                    if !deferred {
-                        return false;
-                    }
-                }
-            }
-
-            if item.path.has_variable_tokens() {
-                item.deferred || !item.path.is_resolved_by(path_fields)
-            } else {
-                item.deferred
-            }
-        }
-
-        let result =
-            inner_is_deferred(config, item, path_fields, index, parent_children_map, cache);
-        cache.insert(index, result);
-
-        result
-    }
-
-    #[allow(clippy::too_many_arguments)]
-    fn recursive_build_items(
-        config: &crate::Config,
-        parent_resolved_item: &crate::ResolvedPathItem,
-        item: &crate::types::PathItem,
-        index: usize,
-        path_fields: &crate::types::PathAttributes,
-        parent_children_map: &std::collections::HashMap<usize, Vec<usize>>,
-        index_key_map: &std::collections::HashMap<usize, crate::FieldKey>,
         resolved_items: &mut Vec<crate::ResolvedPathItem>,
         is_deferred_cache: &mut std::collections::HashMap<usize, bool>,
     ) -> Result<(), crate::Error> {
         if !item.path.is_resolved_by(path_fields) {
             return Ok(());
         }
-        let value = {
-            let mut path_part = String::new();
-            item.path
-                .draw(&mut path_part, path_fields, &config.resolvers)?;
-
-            parent_resolved_item.value.join(path_part)
-        };
-        let permission = match item.permission {
-            crate::types::Permission::Inherit => parent_resolved_item.permission,
-            _ => item.permission,
-        };
-        let owner = match item.owner {
-            crate::types::Owner::Inherit => parent_resolved_item.owner,
-            _ => item.owner,
-        };
-        let path_type = item.path_type;
-        let key = index_key_map.get(&index).cloned();
         let deferred = is_deferred(
             config,
             item,
@@ -319,17 +743,14 @@ pub fn get_workspace(
             parent_children_map,
             is_deferred_cache,
         );
-        let metadata = item.metadata.clone();
-
-        let resolved_item = crate::ResolvedPathItem {
-            key,
-            value,
-            permission,
-            owner,
-            path_type,
+        let resolved_item = resolve_item(
+            config,
+            parent_resolved_item,
+            item,
+            index,
+            path_fields,
             deferred,
-            metadata,
-        };
+        )?;
 
         let child_indexes = parent_children_map.get(&index);
 
@@ -343,7 +764,6 @@ pub fn get_workspace(
                     *child_index,
                     path_fields,
                     parent_children_map,
-                    index_key_map,
                     resolved_items,
                     is_deferred_cache,
                 )?;
@@ -356,32 +776,20 @@ pub fn get_workspace(
     }
 
     let mut resolved_items = Vec::new();
-    let index_key_map = config
-        .item_map
-        .iter()
-        .map(|(k, v)| (*v, k.to_owned()))
-        .collect::<std::collections::HashMap<_, _>>();
     let mut is_deferred_cache = std::collections::HashMap::with_capacity(config.items.len());
 
-    for (item, index) in queue.into_iter() {
-        let key = index_key_map.get(&index).cloned();
-        let resolved_item = crate::ResolvedPathItem {
-            key,
-            value: std::path::PathBuf::new(),
-            permission: item.permission,
-            owner: item.owner,
-            path_type: item.path_type,
-            deferred: item.deferred,
-            metadata: item.metadata.clone(),
-        };
+    for (index, item) in config.items.iter().enumerate() {
+        if item.parent.is_some() {
+            continue;
+        }
+
         recursive_build_items(
             config,
-            &resolved_item,
+            &root_parent_item(config, item, index),
             item,
             index,
             path_fields,
             &parent_children_map,
-            &index_key_map,
             &mut resolved_items,
             &mut is_deferred_cache,
         )?;
@@ -415,12 +823,404 @@ pub fn get_workspace(
     Ok(filtered_resolved_items)
 }
 
+/// Map each item's index to the indexes of its children, in the order of the items.
+fn parent_children_map(config: &crate::Config) -> std::collections::HashMap<usize, Vec<usize>> {
+    let mut parent_children_map = std::collections::HashMap::new();
+
+    for (index, item) in config.items.iter().enumerate() {
+        if let Some(parent_index) = item.parent {
+            parent_children_map
+                .entry(parent_index)
+                .or_insert(Vec::new())
+                .push(index);
+        }
+    }
+
+    parent_children_map
+}
+
+fn is_deferred(
+    config: &crate::Config,
+    item: &crate::types::PathItem,
+    path_fields: &crate::types::PathAttributes,
+    index: usize,
+    parent_children_map: &std::collections::HashMap<usize, Vec<usize>>,
+    cache: &mut std::collections::HashMap<usize, bool>,
+) -> bool {
+    if let Some(deferred) = cache.get(&index) {
+        return *deferred;
+    }
+
+    fn inner_is_deferred(
+        config: &crate::Config,
+        item: &crate::types::PathItem,
+        path_fields: &crate::types::PathAttributes,
+        index: usize,
+        parent_children_map: &std::collections::HashMap<usize, Vec<usize>>,
+        cache: &mut std::collections::HashMap<usize, bool>,
+    ) -> bool {
+        // Rules for deferring:
+        //  - If the path has a variable token, then it may be deferred if explicitly marked as
+        //    deferred, or cannot be resolved.
+        //  - If the path does not have a variable token, and is not explicitly marked as deferred,
+        //    then it is not deferred.
+        //  - If any of the child paths for this item are not deferred using the above rules, then
+        //    it is not deferred.
+        //  - Otherwise, it is deferred.
+        let child_indexes = parent_children_map.get(&index);
+
+        if let Some(child_indexes) = child_indexes {
+            for child_index in child_indexes.iter() {
+                let child_item = &config.items[*child_index];
+                let deferred = is_deferred(
+                    config,
+                    child_item,
+                    path_fields,
+                    *child_index,
+                    parent_children_map,
+                    cache,
+                );
+
+                if !deferred {
+                    return false;
+                }
+            }
+        }
+
+        if item.path.has_variable_tokens() {
+            item.deferred || !item.path.is_resolved_by(path_fields)
+        } else {
+            item.deferred
+        }
+    }
+
+    let result = inner_is_deferred(config, item, path_fields, index, parent_children_map, cache);
+    cache.insert(index, result);
+
+    result
+}
+
+/// The item that a root item is resolved against, so the root inherits its own permission and
+/// owner.
+fn root_parent_item(
+    config: &crate::Config,
+    item: &crate::types::PathItem,
+    index: usize,
+) -> crate::ResolvedPathItem {
+    crate::ResolvedPathItem {
+        key: config.index_key_map.get(&index).cloned(),
+        value: std::path::PathBuf::new(),
+        permission: item.permission.clone(),
+        owner: item.owner.clone(),
+        path_type: item.path_type,
+        deferred: item.deferred,
+        order: item.order,
+        metadata: item.metadata.clone(),
+        redacted_value: None,
+    }
+}
+
+/// Resolve an item under its resolved parent. The item must be resolved by the fields.
+fn resolve_item(
+    config: &crate::Config,
+    parent_resolved_item: &crate::ResolvedPathItem,
+    item: &crate::types::PathItem,
+    index: usize,
+    path_fields: &crate::types::PathAttributes,
+    deferred: bool,
+) -> Result<crate::ResolvedPathItem, crate::Error> {
+    let value = {
+        let mut path_part = String::new();
+        item.path
+            .draw(&mut path_part, path_fields, &config.resolvers)?;
+
+        parent_resolved_item.value.join(path_part)
+    };
+    let redacted_value = if config.secret_fields.is_empty() {
+        None
+    } else {
+        let mut path_part = String::new();
+        item.path.draw_redacted(
+            &mut path_part,
+            path_fields,
+            &config.resolvers,
+            &config.secret_fields,
+        )?;
+
+        let parent_value = match &parent_resolved_item.redacted_value {
+            Some(parent_value) => parent_value,
+            None => &parent_resolved_item.value,
+        };
+        Some(parent_value.join(path_part))
+    };
+    let permission = match item.permission {
+        crate::types::Permission::Inherit => parent_resolved_item.permission.clone(),
+        _ => item.permission.clone(),
+    };
+    let owner = match item.owner {
+        crate::types::Owner::Inherit => parent_resolved_item.owner.clone(),
+        _ => item.owner.clone(),
+    };
+    let path_type = item.path_type;
+    let key = config.index_key_map.get(&index).cloned();
+    let order = item.order;
+    let metadata = item.metadata.clone();
+
+    Ok(crate::ResolvedPathItem {
+        key,
+        value,
+        permission,
+        owner,
+        path_type,
+        deferred,
+        order,
+        metadata,
+        redacted_value,
+    })
+}
+
+/// Get all of the path items that would be created, seeded with a profile's default fields.
+///
+/// This is the same as [get_workspace], but the fields from the profile are used for any field
+/// that is not in the input fields. See [Config::profile_fields](crate::Config::profile_fields).
+///
+/// # Errors
+///
+/// - The profile, if given, must be in the config.
+pub fn get_workspace_with_profile(
+    config: &crate::Config,
+    path_fields: &crate::types::PathAttributes,
+    profile: Option<&str>,
+) -> Result<Vec<crate::ResolvedPathItem>, crate::Error> {
+    get_workspace(config, &config.profile_fields(profile, path_fields)?)
+}
+
+/// Get the path items that would be created with the `create_workspace` function as a tree.
+///
+/// This resolves the same items as [get_workspace], but nests each item under the closest resolved
+/// item that contains it. A config may have more than one root (for example, multiple relative
+/// roots), so the root nodes are returned as a list sorted by their path.
+///
+/// # Example
+///
+/// ```rust
+/// # use openpathresolver::{ConfigBuilder, get_workspace_tree, Owner, PathItemArgs, PathType, Permission};
+/// let config = ConfigBuilder::new()
+///     .add_path_item(PathItemArgs {
+///         key: "key".try_into().unwrap(),
+///         path: "/path/to/{thing}".into(),
+///         parent: None,
+///         permission: Permission::default(),
+///         owner: Owner::default(),
+///         path_type: PathType::default(),
+///         deferred: false,
+///         order: 0,
+///         description: None,
+///         metadata: std::collections::HashMap::new(),
+///     })
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let fields = {
+///     let mut fields = std::collections::HashMap::new();
+///     fields.insert("thing".try_into().unwrap(), "value".into());
+///
+///     fields
+/// };
+///
+/// let roots = get_workspace_tree(&config, &fields).unwrap();
+///
+/// assert_eq!(roots.len(), 1);
+/// assert_eq!(roots[0].children()[0].item().value(), std::path::Path::new("/path"));
+/// ```
+pub fn get_workspace_tree(
+    config: &crate::Config,
+    path_fields: &crate::types::PathAttributes,
+) -> Result<Vec<crate::WorkspaceNode>, crate::Error> {
+    let resolved_items = get_workspace(config, path_fields)?;
+    let path_index_map = resolved_items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| (item.value.as_path(), index))
+        .collect::<std::collections::HashMap<_, _>>();
+    let mut root_indexes = Vec::new();
+    let mut parent_children_map = std::collections::HashMap::new();
+
+    for (index, item) in resolved_items.iter().enumerate() {
+        let parent_index = item
+            .value
+            .ancestors()
+            .skip(1)
+            .find_map(|ancestor| path_index_map.get(ancestor));
+
+        match parent_index {
+            Some(parent_index) => parent_children_map
+                .entry(*parent_index)
+                .or_insert(Vec::new())
+                .push(index),
+            None => root_indexes.push(index),
+        }
+    }
+
+    // The workspace is already sorted by path, but the nodes are sorted here too, so the order of
+    // the tree does not depend on it.
+    let by_path = |a: &usize, b: &usize| resolved_items[*a].value.cmp(&resolved_items[*b].value);
+    root_indexes.sort_by(by_path);
+
+    for child_indexes in parent_children_map.values_mut() {
+        child_indexes.sort_by(by_path);
+    }
+
+    fn build_node(
+        index: usize,
+        resolved_items: &[crate::ResolvedPathItem],
+        parent_children_map: &std::collections::HashMap<usize, Vec<usize>>,
+    ) -> crate::WorkspaceNode {
+        let children = match parent_children_map.get(&index) {
+            Some(child_indexes) => child_indexes
+                .iter()
+                .map(|child_index| build_node(*child_index, resolved_items, parent_children_map))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        crate::WorkspaceNode {
+            item: resolved_items[index].clone(),
+            children,
+        }
+    }
+
+    Ok(root_indexes
+        .into_iter()
+        .map(|index| build_node(index, &resolved_items, &parent_children_map))
+        .collect())
+}
+
+/// Group the resolved path items by their owner.
+///
+/// This is useful for applying the ownership in batches, such as a single `chown` for all of the
+/// [Owner::Project](crate::Owner::Project) paths. The items keep their order within each group.
+pub fn group_by_owner(
+    items: &[crate::ResolvedPathItem],
+) -> std::collections::HashMap<crate::Owner, Vec<&crate::ResolvedPathItem>> {
+    let mut groups = std::collections::HashMap::new();
+
+    for item in items {
+        groups
+            .entry(item.owner.clone())
+            .or_insert(Vec::new())
+            .push(item);
+    }
+
+    groups
+}
+
+/// Sort the resolved path items by their POSIX style paths, so the order is the same on every
+/// platform.
+///
+/// The [get_workspace] order follows the native path ordering, which can differ between
+/// platforms. This sorts by the path components after replacing `\` with `/`, comparing each
+/// component byte by byte, so a plan that is shared between machines is always in the same order.
+/// The parents are still before their children.
+pub fn sort_workspace_posix(items: &mut [crate::ResolvedPathItem]) {
+    items.sort_by_cached_key(|item| {
+        item.value
+            .to_string_lossy()
+            .replace('\\', "/")
+            .split('/')
+            .filter(|component| !component.is_empty())
+            .map(String::from)
+            .collect::<Vec<_>>()
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Owner, PathItemArgs, PathType, Permission};
 
     use super::*;
 
+    fn path_item_args(key: &str, path: &str, parent: Option<&str>) -> PathItemArgs {
+        PathItemArgs {
+            key: key.try_into().unwrap(),
+            path: path.into(),
+            parent: parent.map(|parent| parent.try_into().unwrap()),
+            permission: Permission::default(),
+            owner: Owner::default(),
+            path_type: PathType::default(),
+            deferred: false,
+            order: 0,
+            description: None,
+            metadata: std::collections::HashMap::new(),
+        }
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    #[test]
+    fn test_audit_workspace_success() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path();
+
+        for name in ["locked", "open", "custom"] {
+            let dir = root_dir.join(name);
+            std::fs::create_dir(&dir).unwrap();
+            std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let mut builder = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap();
+
+        for (name, permission) in [
+            ("locked", Permission::ReadOnly),
+            ("open", Permission::ReadWrite),
+            ("custom", Permission::Custom("editorial".into())),
+            ("missing", Permission::ReadOnly),
+        ] {
+            builder = builder
+                .add_path_item(PathItemArgs {
+                    key: name.try_into().unwrap(),
+                    path: name.into(),
+                    parent: Some("root".try_into().unwrap()),
+                    permission,
+                    owner: Owner::default(),
+                    path_type: PathType::default(),
+                    deferred: false,
+                    order: 0,
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                })
+                .unwrap();
+        }
+
+        let config = builder.build().unwrap();
+        let mismatches = audit_workspace(&config, &crate::types::PathAttributes::new()).unwrap();
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].0.value(), root_dir.join("locked"));
+        assert_eq!(
+            mismatches[0].1,
+            PermissionMismatch {
+                expected: Permission::ReadOnly,
+                actual_mode: 0o755,
+            }
+        );
+    }
+
     #[test]
     fn test_get_workspace_success() {
         let config = crate::ConfigBuilder::new()
@@ -432,6 +1232,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -443,6 +1245,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -454,6 +1258,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -465,6 +1271,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -504,6 +1312,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_workspace_disjoint_roots_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                deferred: true,
+                ..path_item_args("renders", "/renders", None)
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                deferred: true,
+                ..path_item_args("render_shot", "{shot}", Some("renders"))
+            })
+            .unwrap()
+            .add_path_item(path_item_args("cache", "/cache", None))
+            .unwrap()
+            .add_path_item(path_item_args("cache_shot", "{shot}", Some("cache")))
+            .unwrap()
+            .add_path_item(path_item_args("renders_old", "/renders_old", None))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("shot".try_into().unwrap(), "sh010".into());
+
+            fields
+        };
+        let resolved_items = get_workspace(&config, &fields).unwrap();
+
+        let values = resolved_items
+            .iter()
+            .map(|item| item.value.to_string_lossy().replace("\\", "/"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, ["/", "/cache", "/cache/sh010", "/renders_old"]);
+    }
+
     #[test]
     fn test_get_workspace_deferred_rules_success() {
         let config = crate::ConfigBuilder::new()
@@ -515,6 +1361,8 @@ mod tests {
                 owner: Owner::Root,
                 path_type: PathType::default(),
                 deferred: true,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -526,6 +1374,8 @@ mod tests {
                 owner: Owner::User,
                 path_type: PathType::File,
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -584,8 +1434,8 @@ mod tests {
                         .to_string_lossy()
                         .replace("\\", "/")
                         .as_ref(),
-                    resolved_item.permission,
-                    resolved_item.owner,
+                    resolved_item.permission.clone(),
+                    resolved_item.owner.clone(),
                     resolved_item.path_type,
                 ),
                 expected
@@ -593,6 +1443,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_get_workspace_custom_owner_permission_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "/path/to".into(),
+                parent: None,
+                permission: Permission::Custom("editorial_rw".to_string()),
+                owner: Owner::Custom("editorial".to_string()),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "child".try_into().unwrap(),
+                path: "a".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let resolved_items = get_workspace(&config, &crate::types::PathAttributes::new()).unwrap();
+
+        for path in ["/path/to", "/path/to/a"] {
+            let resolved_item = resolved_items
+                .iter()
+                .find(|item| item.value.to_string_lossy().replace("\\", "/") == path)
+                .unwrap();
+
+            assert_eq!(
+                resolved_item.permission(),
+                &Permission::Custom("editorial_rw".to_string())
+            );
+            assert_eq!(
+                resolved_item.owner(),
+                &Owner::Custom("editorial".to_string())
+            );
+        }
+    }
+
     #[test]
     fn test_get_workspace_with_unresolved_value_success() {
         let config = crate::ConfigBuilder::new()
@@ -604,6 +1505,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -615,6 +1518,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -644,28 +1549,398 @@ mod tests {
         }
     }
 
-    #[tokio::test]
-    async fn test_create_workspace_success() {
+    #[test]
+    fn test_get_workspace_repeated_calls_success() {
         let config = crate::ConfigBuilder::new()
             .add_path_item(PathItemArgs {
-                key: "key1".try_into().unwrap(),
-                path: "/path/to/{thing}".into(),
+                key: "root".try_into().unwrap(),
+                path: "/path/to".into(),
                 parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
             .add_path_item(PathItemArgs {
-                key: "key2".try_into().unwrap(),
-                path: "/path/to/a/{thing}".into(),
-                parent: None,
+                key: "child".try_into().unwrap(),
+                path: "a/{thing}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // The index to key map is built once with the config instead of once per call.
+        assert_eq!(config.index_key_map.len(), config.item_map.len());
+
+        for (key, index) in config.item_map.iter() {
+            assert_eq!(config.index_key_map.get(index), Some(key));
+        }
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+        let first = get_workspace(&config, &fields).unwrap();
+        let second = get_workspace(&config, &fields).unwrap();
+
+        assert_eq!(first.len(), second.len());
+
+        for (first, second) in first.iter().zip(second.iter()) {
+            assert_eq!(first.key, second.key);
+            assert_eq!(first.value, second.value);
+        }
+
+        let keys = first
+            .iter()
+            .filter_map(|item| item.key().map(|key| key.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(keys, ["root", "child"]);
+    }
+
+    #[test]
+    fn test_get_workspace_secret_field_redacted_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "/path/{token}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "work".try_into().unwrap(),
+                path: "{user}/work".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .mark_field_secret("token")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("token".try_into().unwrap(), "hunter2".into());
+        fields.insert("user".try_into().unwrap(), "alice".into());
+
+        let resolved_items = get_workspace(&config, &fields).unwrap();
+        let work = resolved_items
+            .iter()
+            .find(|item| item.key().map(|key| key.as_str()) == Some("work"))
+            .unwrap();
+
+        assert_eq!(
+            work.value(),
+            std::path::Path::new("/path/hunter2/alice/work")
+        );
+
+        let debug = format!("{work:?}");
+
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains(&format!(
+            "{:?}",
+            std::path::Path::new("/path/***/alice/work")
+        )));
+    }
+
+    #[test]
+    fn test_get_workspace_with_profile_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "{root}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_profile(
+                "dev",
+                [("root".try_into().unwrap(), "/mnt/dev".into())]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()
+            .unwrap();
+
+        let fields = crate::types::PathAttributes::new();
+
+        let resolved_items = get_workspace_with_profile(&config, &fields, Some("dev")).unwrap();
+
+        assert_eq!(resolved_items.len(), 1);
+        assert_eq!(resolved_items[0].value(), std::path::Path::new("/mnt/dev"));
+        assert!(
+            get_workspace_with_profile(&config, &fields, None)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_sort_workspace_posix() {
+        let mut items = [
+            "/proj/a-b",
+            "\\proj\\a\\b",
+            "/proj/a",
+            "/proj/B",
+            "/proj",
+            "/",
+        ]
+        .into_iter()
+        .map(|path| {
+            crate::ResolvedPathItem::new(
+                None,
+                path,
+                Permission::default(),
+                Owner::default(),
+                PathType::default(),
+                false,
+                0,
+                std::collections::HashMap::new(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+        sort_workspace_posix(&mut items);
+
+        assert_eq!(
+            items
+                .iter()
+                .map(|item| item.value().to_string_lossy().into_owned())
+                .collect::<Vec<_>>(),
+            [
+                "/",
+                "/proj",
+                "/proj/B",
+                "/proj/a",
+                "\\proj\\a\\b",
+                "/proj/a-b"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_owner_success() {
+        let items = [
+            ("/proj", Owner::Root),
+            ("/proj/shots", Owner::Project),
+            ("/proj/shots/sh010", Owner::Project),
+            ("/proj/shots/sh010/work", Owner::User),
+            ("/proj/edit", Owner::Custom("editorial".to_string())),
+        ]
+        .into_iter()
+        .map(|(path, owner)| {
+            crate::ResolvedPathItem::new(
+                None,
+                path,
+                Permission::default(),
+                owner,
+                PathType::default(),
+                false,
+                0,
+                std::collections::HashMap::new(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+        let groups = group_by_owner(&items);
+        let paths = |owner: &Owner| {
+            groups[owner]
+                .iter()
+                .map(|item| item.value())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(groups.len(), 4);
+        assert_eq!(paths(&Owner::Root), vec![std::path::Path::new("/proj")]);
+        assert_eq!(
+            paths(&Owner::Project),
+            vec![
+                std::path::Path::new("/proj/shots"),
+                std::path::Path::new("/proj/shots/sh010"),
+            ]
+        );
+        assert_eq!(
+            paths(&Owner::User),
+            vec![std::path::Path::new("/proj/shots/sh010/work")]
+        );
+        assert_eq!(
+            paths(&Owner::Custom("editorial".to_string())),
+            vec![std::path::Path::new("/proj/edit")]
+        );
+        assert!(!groups.contains_key(&Owner::Inherit));
+    }
+
+    #[test]
+    fn test_get_workspace_tree_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key1".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key2".try_into().unwrap(),
+                path: "/path/to/a/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key3".try_into().unwrap(),
+                path: "/path/to/b/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key4".try_into().unwrap(),
+                path: "/path/to/c".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let fields = {
+            let mut fields = crate::types::PathAttributes::new();
+            fields.insert("thing".try_into().unwrap(), "value".into());
+
+            fields
+        };
+        let roots = get_workspace_tree(&config, &fields).unwrap();
+
+        fn flatten(node: &crate::WorkspaceNode, depth: usize, out: &mut Vec<(usize, String)>) {
+            out.push((
+                depth,
+                node.item().value().to_string_lossy().replace("\\", "/"),
+            ));
+
+            for child in node.children() {
+                flatten(child, depth + 1, out);
+            }
+        }
+
+        let mut result = Vec::new();
+
+        for root in roots.iter() {
+            flatten(root, 0, &mut result);
+        }
+
+        let expected = [
+            (0, "/"),
+            (1, "/path"),
+            (2, "/path/to"),
+            (3, "/path/to/a"),
+            (4, "/path/to/a/value"),
+            (3, "/path/to/b"),
+            (4, "/path/to/b/value"),
+            (3, "/path/to/c"),
+            (3, "/path/to/value"),
+        ];
+
+        assert_eq!(roots.len(), 1);
+        assert_eq!(
+            result,
+            expected
+                .iter()
+                .map(|(depth, path)| (*depth, path.to_string()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            roots[0].children()[0].children()[0].children()[3]
+                .item()
+                .key()
+                .map(|k| k.as_str()),
+            Some("key1")
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_create_workspace_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "key1".try_into().unwrap(),
+                path: "/path/to/{thing}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "key2".try_into().unwrap(),
+                path: "/path/to/a/{thing}".into(),
+                parent: None,
                 permission: Permission::default(),
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -677,6 +1952,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: std::collections::HashMap::new(),
             })
             .unwrap()
@@ -720,6 +1997,7 @@ mod tests {
         .unwrap();
     }
 
+    #[cfg(feature = "std")]
     #[tokio::test]
     async fn test_create_workspace_metadata_success() {
         let config = crate::ConfigBuilder::new()
@@ -731,6 +2009,8 @@ mod tests {
                 owner: Owner::default(),
                 path_type: PathType::default(),
                 deferred: false,
+                order: 0,
+                description: None,
                 metadata: [("test".to_string(), crate::MetadataValue::Integer(123))]
                     .into_iter()
                     .collect(),
@@ -784,4 +2064,800 @@ mod tests {
         .await
         .unwrap();
     }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_create_workspace_order_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "/path/to".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "content".try_into().unwrap(),
+                path: "a_content".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "lock".try_into().unwrap(),
+                path: "z.lock".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: -1,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        struct Func(std::sync::Arc<std::sync::Mutex<Vec<std::path::PathBuf>>>);
+
+        #[async_trait::async_trait]
+        impl CreateWorkspaceIoFunction for Func {
+            async fn call(
+                &self,
+                _config: std::sync::Arc<crate::Config>,
+                _template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+                path_item: crate::ResolvedPathItem,
+            ) -> Result<(), crate::Error> {
+                self.0.lock().unwrap().push(path_item.value().to_path_buf());
+
+                Ok(())
+            }
+        }
+
+        let created = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        create_workspace(
+            std::sync::Arc::new(config),
+            &crate::types::PathAttributes::new(),
+            std::sync::Arc::new(crate::types::TemplateAttributes::new()),
+            Func(created.clone()),
+        )
+        .await
+        .unwrap();
+
+        let created = created.lock().unwrap();
+        let lock_index = created
+            .iter()
+            .position(|path| path.ends_with("z.lock"))
+            .unwrap();
+        let content_index = created
+            .iter()
+            .position(|path| path.ends_with("a_content"))
+            .unwrap();
+
+        assert!(lock_index < content_index);
+    }
+
+    #[test]
+    fn test_plan_workspace_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "/path/to".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "content".try_into().unwrap(),
+                path: "a_content/{thing}".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "lock".try_into().unwrap(),
+                path: "z.lock".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::File,
+                deferred: false,
+                order: -1,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "cache".try_into().unwrap(),
+                path: "cache".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: true,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("thing".try_into().unwrap(), "value".into());
+
+        let plan = plan_workspace(&config, &fields).unwrap();
+
+        assert_eq!(
+            plan.iter()
+                .map(|item| item.value().to_string_lossy().replace("\\", "/"))
+                .collect::<Vec<_>>(),
+            vec![
+                "/",
+                "/path",
+                "/path/to",
+                "/path/to/z.lock",
+                "/path/to/a_content",
+                "/path/to/a_content/value",
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_create_workspace_with_options_collect_errors_success() {
+        let mut builder = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: "/path/to".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap();
+
+        for index in 0..5 {
+            builder = builder
+                .add_path_item(PathItemArgs {
+                    key: format!("item{index}").try_into().unwrap(),
+                    path: format!("item_{index}").into(),
+                    parent: Some("root".try_into().unwrap()),
+                    permission: Permission::default(),
+                    owner: Owner::default(),
+                    path_type: PathType::File,
+                    deferred: false,
+                    order: 0,
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                })
+                .unwrap();
+        }
+
+        let config = std::sync::Arc::new(builder.build().unwrap());
+
+        struct Func;
+
+        #[async_trait::async_trait]
+        impl CreateWorkspaceIoFunction for Func {
+            async fn call(
+                &self,
+                _config: std::sync::Arc<crate::Config>,
+                _template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+                path_item: crate::ResolvedPathItem,
+            ) -> Result<(), crate::Error> {
+                if path_item.value().ends_with("item_1") || path_item.value().ends_with("item_3") {
+                    return Err(crate::Error::new("Could not create the path."));
+                }
+
+                Ok(())
+            }
+        }
+
+        let err = create_workspace(
+            config.clone(),
+            &crate::types::PathAttributes::new(),
+            std::sync::Arc::new(crate::types::TemplateAttributes::new()),
+            Func,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(err.to_string(), "Could not create the path.");
+
+        let options = crate::CreateWorkspaceOptions {
+            error_mode: crate::CreateWorkspaceErrorMode::CollectErrors,
+            require_keys: Vec::new(),
+            verify: false,
+        };
+        let failures = create_workspace_with_options(
+            config,
+            &crate::types::PathAttributes::new(),
+            std::sync::Arc::new(crate::types::TemplateAttributes::new()),
+            Func,
+            &options,
+        )
+        .await
+        .unwrap();
+
+        let mut failed_keys = failures
+            .iter()
+            .map(|(item, err)| {
+                assert_eq!(err.to_string(), "Could not create the path.");
+                item.key().unwrap().as_str()
+            })
+            .collect::<Vec<_>>();
+        failed_keys.sort();
+
+        assert_eq!(failed_keys, vec!["item1", "item3"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case(true, Some(vec!["item_1", "item_3"]))]
+    #[case(false, None)]
+    #[tokio::test]
+    async fn test_create_workspace_with_options_verify(
+        #[case] verify: bool,
+        #[case] expected_missing: Option<Vec<&str>>,
+    ) {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path().to_path_buf();
+        let mut builder = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.clone(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap();
+
+        for index in 0..5 {
+            builder = builder
+                .add_path_item(PathItemArgs {
+                    key: format!("item{index}").try_into().unwrap(),
+                    path: format!("item_{index}").into(),
+                    parent: Some("root".try_into().unwrap()),
+                    permission: Permission::default(),
+                    owner: Owner::default(),
+                    path_type: PathType::default(),
+                    deferred: false,
+                    order: 0,
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                })
+                .unwrap();
+        }
+
+        let config = std::sync::Arc::new(builder.build().unwrap());
+
+        struct Func;
+
+        #[async_trait::async_trait]
+        impl CreateWorkspaceIoFunction for Func {
+            async fn call(
+                &self,
+                _config: std::sync::Arc<crate::Config>,
+                _template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+                path_item: crate::ResolvedPathItem,
+            ) -> Result<(), crate::Error> {
+                // Skip a couple of paths without reporting an error, like a buggy IO function.
+                if path_item.value().ends_with("item_1") || path_item.value().ends_with("item_3") {
+                    return Ok(());
+                }
+
+                tokio::fs::create_dir_all(path_item.value()).await?;
+
+                Ok(())
+            }
+        }
+
+        let options = crate::CreateWorkspaceOptions {
+            error_mode: crate::CreateWorkspaceErrorMode::FailFast,
+            require_keys: Vec::new(),
+            verify,
+        };
+        let result = create_workspace_with_options(
+            config,
+            &crate::types::PathAttributes::new(),
+            std::sync::Arc::new(crate::types::TemplateAttributes::new()),
+            Func,
+            &options,
+        )
+        .await;
+
+        match expected_missing {
+            Some(expected_missing) => {
+                let expected = expected_missing
+                    .iter()
+                    .map(|name| format!("{:?}", root_dir.join(name)))
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    result.unwrap_err().to_string(),
+                    format!("The paths were not created: {}.", expected.join(", "))
+                );
+            }
+            None => assert!(result.unwrap().is_empty()),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_create_workspace_with_options_verify_collect_errors() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let root_dir = tmp_dir.path().to_path_buf();
+        let mut builder = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: root_dir.clone(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap();
+
+        for index in 0..3 {
+            builder = builder
+                .add_path_item(PathItemArgs {
+                    key: format!("item{index}").try_into().unwrap(),
+                    path: format!("item_{index}").into(),
+                    parent: Some("root".try_into().unwrap()),
+                    permission: Permission::default(),
+                    owner: Owner::default(),
+                    path_type: PathType::default(),
+                    deferred: false,
+                    order: 0,
+                    description: None,
+                    metadata: std::collections::HashMap::new(),
+                })
+                .unwrap();
+        }
+
+        let config = std::sync::Arc::new(builder.build().unwrap());
+
+        struct Func;
+
+        #[async_trait::async_trait]
+        impl CreateWorkspaceIoFunction for Func {
+            async fn call(
+                &self,
+                _config: std::sync::Arc<crate::Config>,
+                _template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+                path_item: crate::ResolvedPathItem,
+            ) -> Result<(), crate::Error> {
+                // Skip one path without reporting an error, and fail on another.
+                if path_item.value().ends_with("item_1") {
+                    return Ok(());
+                }
+
+                if path_item.value().ends_with("item_2") {
+                    return Err(crate::Error::new("Failed."));
+                }
+
+                tokio::fs::create_dir_all(path_item.value()).await?;
+
+                Ok(())
+            }
+        }
+
+        let options = crate::CreateWorkspaceOptions {
+            error_mode: crate::CreateWorkspaceErrorMode::CollectErrors,
+            require_keys: Vec::new(),
+            verify: true,
+        };
+        let err = create_workspace_with_options(
+            config,
+            &crate::types::PathAttributes::new(),
+            std::sync::Arc::new(crate::types::TemplateAttributes::new()),
+            Func,
+            &options,
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "The paths were not created: {:?}. The paths that failed: {:?} (Failed.).",
+                root_dir.join("item_1"),
+                root_dir.join("item_2")
+            )
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case("scene", None)]
+    #[case("cache", Some("The path for \"cache\" is deferred."))]
+    #[tokio::test]
+    async fn test_create_path(#[case] key: &str, #[case] expected_err: Option<&str>) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(path_item_args("shot", "/proj/{shot}", None))
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                deferred: true,
+                ..path_item_args("work", "work", Some("shot"))
+            })
+            .unwrap()
+            .add_path_item(path_item_args("scene", "{scene}.blend", Some("work")))
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                deferred: true,
+                ..path_item_args("cache", "cache", Some("shot"))
+            })
+            .unwrap()
+            .add_path_item(path_item_args("sim", "sim/{task}", Some("cache")))
+            .unwrap()
+            .add_path_item(path_item_args("comp", "comp", Some("shot")))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        struct Func(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+        #[async_trait::async_trait]
+        impl CreateWorkspaceIoFunction for Func {
+            async fn call(
+                &self,
+                _config: std::sync::Arc<crate::Config>,
+                _template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+                path_item: crate::ResolvedPathItem,
+            ) -> Result<(), crate::Error> {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(path_item.value().to_string_lossy().replace("\\", "/"));
+
+                Ok(())
+            }
+        }
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "sh010".into());
+        fields.insert("scene".try_into().unwrap(), "layout".into());
+        let calls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let result = create_path(
+            std::sync::Arc::new(config),
+            key,
+            &fields,
+            std::sync::Arc::new(crate::types::TemplateAttributes::new()),
+            Func(calls.clone()),
+        )
+        .await;
+
+        match expected_err {
+            Some(expected_err) => {
+                assert_eq!(result.unwrap_err().to_string(), expected_err);
+                assert!(calls.lock().unwrap().is_empty());
+            }
+            None => {
+                result.unwrap();
+                assert_eq!(
+                    *calls.lock().unwrap(),
+                    [
+                        "/",
+                        "/proj",
+                        "/proj/sh010",
+                        "/proj/sh010/work",
+                        "/proj/sh010/work/layout.blend"
+                    ]
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_create_path_unrelated_branch_success() {
+        let config = crate::ConfigBuilder::new()
+            .add_integer_resolver("version", 3)
+            .unwrap()
+            .add_path_item(path_item_args("shot", "/proj/shots/{shot}", None))
+            .unwrap()
+            .add_path_item(path_item_args("publish", "/proj/publish/v{version}", None))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        struct Func(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+        #[async_trait::async_trait]
+        impl CreateWorkspaceIoFunction for Func {
+            async fn call(
+                &self,
+                _config: std::sync::Arc<crate::Config>,
+                _template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+                path_item: crate::ResolvedPathItem,
+            ) -> Result<(), crate::Error> {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(path_item.value().to_string_lossy().replace("\\", "/"));
+
+                Ok(())
+            }
+        }
+
+        // The version is not valid, so the whole workspace cannot be resolved.
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "sh010".into());
+        fields.insert("version".try_into().unwrap(), "latest".into());
+        assert!(get_workspace(&config, &fields).is_err());
+
+        let calls = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        create_path(
+            std::sync::Arc::new(config),
+            "shot",
+            &fields,
+            std::sync::Arc::new(crate::types::TemplateAttributes::new()),
+            Func(calls.clone()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            ["/", "/proj", "/proj/shots", "/proj/shots/sh010"]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[rstest::rstest]
+    #[case("shot", None)]
+    #[case(
+        "task",
+        Some("Required key \"task\" would not be created: Could not find \"task\" in the fields.")
+    )]
+    #[case(
+        "deferred",
+        Some("Required key \"deferred\" would not be created: The path is deferred.")
+    )]
+    #[case(
+        "composite",
+        Some(
+            "Required key \"composite\" would not be created: The key is a composite item, which is not created."
+        )
+    )]
+    #[tokio::test]
+    async fn test_create_workspace_with_options_require_keys(
+        #[case] required_key: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "shot".try_into().unwrap(),
+                path: "/proj/{shot}".into(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "task".try_into().unwrap(),
+                path: "{task}".into(),
+                parent: Some("shot".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "deferred".try_into().unwrap(),
+                path: "cache".into(),
+                parent: Some("shot".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: true,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_composite_item(
+                "composite",
+                vec!["shot".try_into().unwrap(), "deferred".try_into().unwrap()],
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        struct Func(std::sync::Arc<std::sync::Mutex<usize>>);
+
+        #[async_trait::async_trait]
+        impl CreateWorkspaceIoFunction for Func {
+            async fn call(
+                &self,
+                _config: std::sync::Arc<crate::Config>,
+                _template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+                _path_item: crate::ResolvedPathItem,
+            ) -> Result<(), crate::Error> {
+                *self.0.lock().unwrap() += 1;
+
+                Ok(())
+            }
+        }
+
+        let mut fields = crate::types::PathAttributes::new();
+        fields.insert("shot".try_into().unwrap(), "sh010".into());
+        let calls = std::sync::Arc::new(std::sync::Mutex::new(0));
+        let options = crate::CreateWorkspaceOptions {
+            error_mode: crate::CreateWorkspaceErrorMode::FailFast,
+            require_keys: vec![required_key.try_into().unwrap()],
+            verify: false,
+        };
+
+        let result = create_workspace_with_options(
+            std::sync::Arc::new(config),
+            &fields,
+            std::sync::Arc::new(crate::types::TemplateAttributes::new()),
+            Func(calls.clone()),
+            &options,
+        )
+        .await;
+
+        match expected {
+            Some(expected) => {
+                assert_eq!(result.unwrap_err().to_string(), expected);
+                assert_eq!(*calls.lock().unwrap(), 0);
+            }
+            None => {
+                assert!(result.unwrap().is_empty());
+                assert_eq!(*calls.lock().unwrap(), 3);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn test_create_workspace_write_if_changed_success() {
+        let tmp_dir = tempfile::tempdir().unwrap();
+
+        let config = crate::ConfigBuilder::new()
+            .add_path_item(PathItemArgs {
+                key: "root".try_into().unwrap(),
+                path: tmp_dir.path().to_path_buf(),
+                parent: None,
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::default(),
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .add_path_item(PathItemArgs {
+                key: "file".try_into().unwrap(),
+                path: "{shot}.txt".into(),
+                parent: Some("root".try_into().unwrap()),
+                permission: Permission::default(),
+                owner: Owner::default(),
+                path_type: PathType::FileTemplate,
+                deferred: false,
+                order: 0,
+                description: None,
+                metadata: std::collections::HashMap::new(),
+            })
+            .unwrap()
+            .build()
+            .unwrap();
+        let config = std::sync::Arc::new(config);
+
+        struct Func {
+            statuses: std::sync::Arc<std::sync::Mutex<Vec<WriteStatus>>>,
+        }
+
+        #[async_trait::async_trait]
+        impl CreateWorkspaceIoFunction for Func {
+            async fn call(
+                &self,
+                _config: std::sync::Arc<crate::Config>,
+                template_fields: std::sync::Arc<crate::types::TemplateAttributes>,
+                path_item: crate::ResolvedPathItem,
+            ) -> Result<(), crate::Error> {
+                if path_item.path_type() != &PathType::FileTemplate {
+                    return Ok(());
+                }
+
+                let rendered = format!(
+                    "frames: {:?}",
+                    template_fields.get(&"frames".try_into().unwrap())
+                );
+                let status = write_if_changed(path_item.value(), rendered)?;
+                self.statuses.lock().unwrap().push(status);
+
+                Ok(())
+            }
+        }
+
+        let mut path_fields = crate::types::PathAttributes::new();
+        path_fields.insert("shot".try_into().unwrap(), "sh010".into());
+        let template_fields = {
+            let mut fields = crate::types::TemplateAttributes::new();
+            fields.insert("frames".try_into().unwrap(), 100.into());
+
+            std::sync::Arc::new(fields)
+        };
+        let statuses = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        for _ in 0..2 {
+            create_workspace(
+                config.clone(),
+                &path_fields,
+                template_fields.clone(),
+                Func {
+                    statuses: statuses.clone(),
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        assert_eq!(
+            *statuses.lock().unwrap(),
+            vec![WriteStatus::Written, WriteStatus::Unchanged]
+        );
+        assert_eq!(
+            std::fs::read_to_string(tmp_dir.path().join("sh010.txt")).unwrap(),
+            "frames: Some(Integer(100))"
+        );
+
+        let status = write_if_changed(tmp_dir.path().join("sh010.txt"), "frames: None").unwrap();
+
+        assert_eq!(status, WriteStatus::Written);
+    }
 }